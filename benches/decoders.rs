@@ -0,0 +1,90 @@
+use tsc_texture_extractor::{gamecube, playstation_2, rle_textures, the_sims_3, xbox};
+
+const WIDTH: usize = 256;
+const HEIGHT: usize = 256;
+
+fn noise(length: usize) -> Vec<u8> {
+    let mut state = 0x2545_F491_4F6C_DD1D_u64;
+    (0..length)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state >> 32) as u8
+        })
+        .collect()
+}
+
+fn playstation_2(c: &mut criterion::Criterion) {
+    let bytes = noise(WIDTH * HEIGHT * 4);
+    let palette = noise(256 * 4);
+
+    let mut group = c.benchmark_group("playstation_2");
+    group.bench_function("rgb5", |b| b.iter(|| playstation_2::decode_rgb5(&bytes, WIDTH, HEIGHT)));
+    group.bench_function("rgb8", |b| b.iter(|| playstation_2::decode_rgb8(&bytes, WIDTH, HEIGHT)));
+    group.bench_function("rgba8", |b| {
+        b.iter(|| playstation_2::decode_rgba8(&bytes, WIDTH, HEIGHT))
+    });
+    group.bench_function("c4", |b| {
+        b.iter(|| playstation_2::decode_c4(&bytes, WIDTH, HEIGHT, &palette))
+    });
+    group.bench_function("c8", |b| {
+        b.iter(|| playstation_2::decode_c8(&bytes, WIDTH, HEIGHT, &palette))
+    });
+    group.finish();
+}
+
+fn gamecube(c: &mut criterion::Criterion) {
+    let bytes = noise(WIDTH * HEIGHT * 4);
+    let palette = noise(256 * 4);
+
+    let mut group = c.benchmark_group("gamecube");
+    group.bench_function("cmpr", |b| b.iter(|| gamecube::decode_cmpr(&bytes, WIDTH, HEIGHT)));
+    group.bench_function("rgb5a3", |b| b.iter(|| gamecube::decode_rgb5a3(&bytes, WIDTH, HEIGHT)));
+    group.bench_function("rgba8", |b| b.iter(|| gamecube::decode_rgba8(&bytes, WIDTH, HEIGHT)));
+    group.bench_function("c4", |b| {
+        b.iter(|| gamecube::decode_c4(&bytes, WIDTH, HEIGHT, &palette))
+    });
+    group.bench_function("c8", |b| {
+        b.iter(|| gamecube::decode_c8(&bytes, WIDTH, HEIGHT, &palette))
+    });
+    group.finish();
+}
+
+fn xbox(c: &mut criterion::Criterion) {
+    let bytes = noise(WIDTH * HEIGHT * 4);
+    let palette = noise(256 * 4);
+
+    let mut group = c.benchmark_group("xbox");
+    group.bench_function("rgba8", |b| b.iter(|| xbox::decode_rgba8(&bytes, WIDTH, HEIGHT)));
+    group.bench_function("rgb5", |b| b.iter(|| xbox::decode_rgb5(&bytes, WIDTH, HEIGHT)));
+    group.bench_function("c8", |b| b.iter(|| xbox::decode_c8(&bytes, WIDTH, HEIGHT, &palette)));
+    group.bench_function("rgba8 non-square", |b| {
+        b.iter(|| xbox::decode_rgba8(&bytes, WIDTH, HEIGHT / 4))
+    });
+    group.bench_function("bc1", |b| b.iter(|| xbox::decode_bc1(&bytes, WIDTH, HEIGHT)));
+    group.bench_function("bc2", |b| b.iter(|| xbox::decode_bc2(&bytes, WIDTH, HEIGHT)));
+    group.finish();
+}
+
+fn rle(c: &mut criterion::Criterion) {
+    let runs = noise(WIDTH * HEIGHT / 8).into_iter().flat_map(|index| [index; 8]).collect::<Vec<_>>();
+
+    let mut rle_c8 = vec![0];
+    rle_c8.extend_from_slice(&noise(256 * 4));
+    rle_c8.extend_from_slice(&[0; 4]);
+    rle_c8.extend_from_slice(&rle_textures::encode(
+        &image::GrayImage::from_raw(256, 256, runs.clone()).unwrap(),
+    ));
+
+    let pixels = runs.iter().flat_map(|index| [*index; 4]).collect::<Vec<_>>();
+    let the_sims_3_rle = the_sims_3::encode_rle(&pixels, 32).unwrap();
+
+    let mut group = c.benchmark_group("rle");
+    group.bench_function("c8", |b| b.iter(|| rle_textures::convert(&rle_c8, true)));
+    group.bench_function("the_sims_3", |b| b.iter(|| the_sims_3::decode_rle(&the_sims_3_rle, 32)));
+    group.finish();
+}
+
+criterion::criterion_group!(benches, playstation_2, gamecube, xbox, rle);
+criterion::criterion_main!(benches);
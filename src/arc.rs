@@ -1,28 +1,301 @@
-pub fn list_files(arc_bytes: &[u8], endianness: crate::Endianness) -> Vec<(String, u32, &[u8])> {
-    let list_address = u32::from_le_bytes(arc_bytes[0..4].try_into().unwrap()) as usize;
+#[derive(Clone)]
+pub struct Entry<'a> {
+    pub name: String,
+    pub id: u32,
+    pub offset: usize,
+    pub bytes: &'a [u8],
+    pub trailer: [u8; 8],
+}
 
-    let file_count = endianness.u32_from_bytes(arc_bytes[list_address..list_address + 4].try_into().unwrap());
+pub struct Entries<'a> {
+    arc_bytes: &'a [u8],
+    endianness: crate::Endianness,
+    list_address: usize,
+    remaining: u32,
+    position: usize,
+}
 
-    let mut files = Vec::new();
+impl<'a> Entries<'a> {
+    fn read_entry(&mut self) -> crate::Result<Entry<'a>> {
+        let (arc_bytes, endianness, list_address) = (self.arc_bytes, self.endianness, self.list_address);
+        let file_list = &arc_bytes[list_address..];
 
-    let file_list = &arc_bytes[list_address..];
-    let mut i = 4;
-    for _ in 0..file_count {
-        let id = endianness.u32_from_bytes(file_list[i..i + 4].try_into().unwrap());
+        let mut i = self.position;
+        let id = crate::bytes::u32(file_list, i, endianness)?;
         i += 4;
-        let address = endianness.u32_from_bytes(file_list[i..i + 4].try_into().unwrap()) as usize;
+        let address = crate::bytes::u32(file_list, i, endianness)? as usize;
         i += 4;
-        let size = endianness.u32_from_bytes(file_list[i..i + 4].try_into().unwrap()) as usize;
+        let size = crate::bytes::u32(file_list, i, endianness)? as usize;
         i += 4;
 
-        let null_position = file_list[i..].iter().position(|x| *x == 0).unwrap();
-        let name = std::str::from_utf8(&file_list[i..i + null_position]).unwrap();
+        let name = crate::bytes::name(file_list, i).map_err(|error| match error {
+            crate::Error::InvalidName { offset } => crate::Error::InvalidName {
+                offset: list_address + offset,
+            },
+            error => error,
+        })?;
+
+        let bytes = crate::bytes::slice(arc_bytes, address, size).map_err(|_| crate::Error::BadOffset {
+            offset: address,
+            length: size,
+            available: arc_bytes.len(),
+        })?;
+
+        i += name.len() + 1;
+
+        let trailer = crate::bytes::array(file_list, i)?;
+        i += 8;
+
+        self.position = i;
+        Ok(Entry {
+            name: name.to_owned(),
+            id,
+            offset: address,
+            bytes,
+            trailer,
+        })
+    }
+}
+
+impl<'a> Iterator for Entries<'a> {
+    type Item = crate::Result<Entry<'a>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        self.remaining -= 1;
+        let entry = self.read_entry();
+        if entry.is_err() {
+            self.remaining = 0;
+        }
+
+        Some(entry)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.remaining as usize))
+    }
+}
+
+pub fn entries(arc_bytes: &[u8], endianness: crate::Endianness) -> crate::Result<Entries<'_>> {
+    let list_address = crate::bytes::u32(arc_bytes, 0, crate::Endianness::Little)? as usize;
+
+    let file_count = crate::bytes::u32(arc_bytes, list_address, endianness)?;
+
+    Ok(Entries {
+        arc_bytes,
+        endianness,
+        list_address,
+        remaining: file_count,
+        position: 4,
+    })
+}
+
+pub fn list_files(arc_bytes: &[u8], endianness: crate::Endianness) -> crate::Result<Vec<Entry<'_>>> {
+    entries(arc_bytes, endianness)?.collect()
+}
+
+pub fn detect_endianness(arc_bytes: &[u8]) -> Option<crate::Endianness> {
+    [crate::Endianness::Little, crate::Endianness::Big]
+        .into_iter()
+        .find(|endianness| list_files(arc_bytes, *endianness).is_ok())
+}
+
+fn u32_field(value: usize) -> crate::Result<u32> {
+    u32::try_from(value).map_err(|_| crate::Error::ArchiveTooLarge { length: value })
+}
+
+fn write_list(
+    arc_bytes: &mut Vec<u8>,
+    entries: &[Entry],
+    addresses: &[u32],
+    endianness: crate::Endianness,
+) -> crate::Result<()> {
+    let list_address = u32_field(arc_bytes.len())?;
+    arc_bytes[0..4].copy_from_slice(&list_address.to_le_bytes());
+
+    arc_bytes.extend_from_slice(&endianness.u32_to_bytes(u32_field(entries.len())?));
+    for (entry, address) in entries.iter().zip(addresses) {
+        arc_bytes.extend_from_slice(&endianness.u32_to_bytes(entry.id));
+        arc_bytes.extend_from_slice(&endianness.u32_to_bytes(*address));
+        arc_bytes.extend_from_slice(&endianness.u32_to_bytes(u32_field(entry.bytes.len())?));
+        arc_bytes.extend_from_slice(entry.name.as_bytes());
+        arc_bytes.push(0);
+        arc_bytes.extend_from_slice(&entry.trailer);
+    }
+
+    u32_field(arc_bytes.len())?;
+    Ok(())
+}
+
+pub fn write_files(entries: &[Entry], endianness: crate::Endianness) -> crate::Result<Vec<u8>> {
+    let mut arc_bytes = vec![0; 4];
 
-        files.push((name.to_owned(), id, &arc_bytes[address..address + size]));
+    let mut addresses = Vec::new();
+    for entry in entries {
+        addresses.push(u32_field(arc_bytes.len())?);
+        arc_bytes.extend_from_slice(entry.bytes);
+    }
+
+    write_list(&mut arc_bytes, entries, &addresses, endianness)?;
+    Ok(arc_bytes)
+}
+
+fn list_end(arc_bytes: &[u8], endianness: crate::Endianness) -> crate::Result<usize> {
+    let mut entries = entries(arc_bytes, endianness)?;
+    for entry in entries.by_ref() {
+        entry?;
+    }
+
+    Ok(entries.list_address + entries.position)
+}
+
+// Entries that still fit before the next entry keep their offset, and the padding between them is copied from the
+// original archive. Entries that grew past that are moved after the last entry, aligned like the original entries.
+pub fn update_files(arc_bytes: &[u8], entries: &[Entry], endianness: crate::Endianness) -> crate::Result<Vec<u8>> {
+    let data_end = crate::bytes::u32(arc_bytes, 0, crate::Endianness::Little)? as usize;
+    let list_end = list_end(arc_bytes, endianness)?;
+    let mut updated_bytes = crate::bytes::slice(arc_bytes, 0, data_end)?.to_vec();
+
+    let mut offsets = entries.iter().map(|entry| entry.offset).collect::<Vec<_>>();
+    offsets.sort_unstable();
+    offsets.dedup();
+    let alignment = offsets.iter().filter(|offset| **offset > 0).fold(2048, |alignment: usize, offset| {
+        alignment.min(1 << offset.trailing_zeros())
+    });
+
+    let mut addresses = Vec::new();
+    let mut relocated = Vec::new();
+    for (index, entry) in entries.iter().enumerate() {
+        let slot_end = offsets
+            .iter()
+            .find(|offset| **offset > entry.offset)
+            .map_or(data_end, |offset| (*offset).min(data_end));
+        if entry.offset >= 4 && entry.offset + entry.bytes.len() <= slot_end {
+            updated_bytes[entry.offset..entry.offset + entry.bytes.len()].copy_from_slice(entry.bytes);
+            addresses.push(u32_field(entry.offset)?);
+        } else {
+            addresses.push(0);
+            relocated.push(index);
+        }
+    }
+
+    for index in relocated {
+        updated_bytes.resize(updated_bytes.len().next_multiple_of(alignment), 0);
+        addresses[index] = u32_field(updated_bytes.len())?;
+        updated_bytes.extend_from_slice(entries[index].bytes);
+    }
+
+    write_list(&mut updated_bytes, entries, &addresses, endianness)?;
+    updated_bytes.extend_from_slice(crate::bytes::tail(arc_bytes, list_end)?);
+    Ok(updated_bytes)
+}
+
+pub fn repack(arc_bytes: &[u8], endianness: crate::Endianness) -> crate::Result<Vec<u8>> {
+    update_files(arc_bytes, &list_files(arc_bytes, endianness)?, endianness)
+}
+
+pub fn check_repack(arc_bytes: &[u8], endianness: crate::Endianness) -> crate::Result<()> {
+    let repacked_bytes = repack(arc_bytes, endianness)?;
+
+    let mismatch = arc_bytes.iter().zip(&repacked_bytes).position(|(original, repacked)| original != repacked);
+    match mismatch {
+        None if arc_bytes.len() == repacked_bytes.len() => Ok(()),
+        offset => Err(crate::Error::RepackMismatch {
+            offset: offset.unwrap_or(arc_bytes.len().min(repacked_bytes.len())),
+            length: arc_bytes.len(),
+            repacked_length: repacked_bytes.len(),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Endianness;
+
+    fn entry<'a>(name: &str, id: u32, offset: usize, bytes: &'a [u8]) -> super::Entry<'a> {
+        super::Entry {
+            name: name.to_owned(),
+            id,
+            offset,
+            bytes,
+            trailer: [id as u8; 8],
+        }
+    }
 
-        i += null_position;
-        i += 9;
+    // Two entries aligned to 16 bytes with non-zero padding, and padding after the entry list.
+    fn padded_archive() -> Vec<u8> {
+        let mut arc_bytes = 48u32.to_le_bytes().to_vec();
+        arc_bytes.resize(16, 0xCC);
+        arc_bytes.extend_from_slice(b"first");
+        arc_bytes.resize(32, 0xAA);
+        arc_bytes.extend_from_slice(b"second!");
+        arc_bytes.resize(48, 0xAA);
+        arc_bytes.extend_from_slice(&2u32.to_be_bytes());
+        for (id, address, size, name) in [(1u32, 16u32, 5u32, "a"), (2, 32, 7, "b")] {
+            for value in [id, address, size] {
+                arc_bytes.extend_from_slice(&value.to_be_bytes());
+            }
+            arc_bytes.extend_from_slice(name.as_bytes());
+            arc_bytes.push(0);
+            arc_bytes.extend_from_slice(&[id as u8; 8]);
+        }
+        arc_bytes.resize(112, 0);
+        arc_bytes
     }
 
-    files
+    #[test]
+    fn packed_round_trip() {
+        let entries = [
+            entry("a", 1, 0, b"first"),
+            entry("b", 2, 0, b"second!"),
+            entry("c", 3, 0, b""),
+        ];
+        for endianness in [Endianness::Little, Endianness::Big] {
+            let arc_bytes = super::write_files(&entries, endianness).unwrap();
+            let listed = super::list_files(&arc_bytes, endianness).unwrap();
+            let listed = listed.iter().map(|entry| (entry.name.as_str(), entry.id, entry.bytes)).collect::<Vec<_>>();
+            assert_eq!(listed, [("a", 1, &b"first"[..]), ("b", 2, b"second!"), ("c", 3, b"")]);
+            assert_eq!(super::repack(&arc_bytes, endianness).unwrap(), arc_bytes);
+            super::check_repack(&arc_bytes, endianness).unwrap();
+        }
+    }
+
+    #[test]
+    fn padded_round_trip() {
+        let arc_bytes = padded_archive();
+        assert!(matches!(super::detect_endianness(&arc_bytes), Some(Endianness::Big)));
+        let offsets = super::list_files(&arc_bytes, Endianness::Big)
+            .unwrap()
+            .iter()
+            .map(|entry| entry.offset)
+            .collect::<Vec<_>>();
+        assert_eq!(offsets, [16, 32]);
+        assert_eq!(super::repack(&arc_bytes, Endianness::Big).unwrap(), arc_bytes);
+        super::check_repack(&arc_bytes, Endianness::Big).unwrap();
+    }
+
+    #[test]
+    fn grown_entry_is_relocated() {
+        let arc_bytes = padded_archive();
+        let mut entries = super::list_files(&arc_bytes, Endianness::Big).unwrap();
+        entries[0].bytes = b"first, but longer";
+        entries[1].bytes = b"2nd";
+
+        let updated_bytes = super::update_files(&arc_bytes, &entries, Endianness::Big).unwrap();
+        let updated = super::list_files(&updated_bytes, Endianness::Big).unwrap();
+        assert_eq!((updated[0].offset, updated[0].bytes), (48, &b"first, but longer"[..]));
+        assert_eq!((updated[1].offset, updated[1].bytes), (32, &b"2nd"[..]));
+        assert_eq!(updated_bytes[4..16], arc_bytes[4..16]);
+        assert!(updated_bytes.ends_with(&[0; 16]));
+    }
+
+    #[test]
+    fn truncated_list_is_an_error() {
+        let arc_bytes = padded_archive();
+        assert!(super::list_files(&arc_bytes[..60], Endianness::Big).is_err());
+        assert!(super::check_repack(&arc_bytes[..60], Endianness::Big).is_err());
+    }
 }
@@ -0,0 +1,69 @@
+use crate::error::Error;
+
+pub fn slice(bytes: &[u8], offset: usize, length: usize) -> crate::Result<&[u8]> {
+    offset.checked_add(length).and_then(|end| bytes.get(offset..end)).ok_or(Error::Truncated {
+        offset,
+        length,
+        available: bytes.len(),
+    })
+}
+
+pub fn tail(bytes: &[u8], offset: usize) -> crate::Result<&[u8]> {
+    bytes.get(offset..).ok_or(Error::Truncated {
+        offset,
+        length: 0,
+        available: bytes.len(),
+    })
+}
+
+pub fn rows_bottom_up(bytes: &[u8], row_length: usize) -> std::iter::Rev<std::slice::ChunksExact<'_, u8>> {
+    bytes.chunks_exact(row_length.max(1)).rev()
+}
+
+pub fn last_offset(bytes: &[u8], length: usize) -> crate::Result<usize> {
+    match bytes.len().checked_sub(length) {
+        Some(offset) => Ok(offset),
+        None => Err(Error::Truncated {
+            offset: 0,
+            length,
+            available: bytes.len(),
+        }),
+    }
+}
+
+pub fn array<const N: usize>(bytes: &[u8], offset: usize) -> crate::Result<[u8; N]> {
+    let mut array = [0; N];
+    array.copy_from_slice(slice(bytes, offset, N)?);
+    Ok(array)
+}
+
+pub fn u8(bytes: &[u8], offset: usize) -> crate::Result<u8> {
+    Ok(slice(bytes, offset, 1)?[0])
+}
+
+pub fn u16(bytes: &[u8], offset: usize, endianness: crate::Endianness) -> crate::Result<u16> {
+    let bytes = slice(bytes, offset, 2)?;
+    Ok(endianness.u16_from_bytes([bytes[0], bytes[1]]))
+}
+
+pub fn u32(bytes: &[u8], offset: usize, endianness: crate::Endianness) -> crate::Result<u32> {
+    let bytes = slice(bytes, offset, 4)?;
+    Ok(endianness.u32_from_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+}
+
+pub fn u64(bytes: &[u8], offset: usize, endianness: crate::Endianness) -> crate::Result<u64> {
+    Ok(endianness.u64_from_bytes(array(bytes, offset)?))
+}
+
+pub fn null_position(bytes: &[u8], offset: usize) -> crate::Result<usize> {
+    tail(bytes, offset)?.iter().position(|x| *x == 0).ok_or(Error::Truncated {
+        offset,
+        length: bytes.len() - offset + 1,
+        available: bytes.len(),
+    })
+}
+
+pub fn name(bytes: &[u8], offset: usize) -> crate::Result<&str> {
+    let null_position = null_position(bytes, offset)?;
+    std::str::from_utf8(&bytes[offset..offset + null_position]).map_err(|_| Error::InvalidName { offset })
+}
@@ -0,0 +1,397 @@
+use std::io::Read;
+
+const CISO_HEADER_LENGTH: usize = 0x8000;
+const CSO_HEADER_LENGTH: usize = 0x18;
+const GCZ_MAGIC: u32 = 0xB10BC001;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Ciso,
+    Gcz,
+    Cso,
+    Zso,
+}
+
+struct Block {
+    offset: usize,
+    length: usize,
+    compressed: bool,
+}
+
+pub struct Image<'a> {
+    bytes: &'a [u8],
+    format: Format,
+    block_size: usize,
+    length: usize,
+    blocks: Vec<Option<Block>>,
+    padding: usize,
+    cache: crate::disc::BlockCache,
+}
+
+fn format(bytes: &[u8]) -> Option<Format> {
+    match crate::bytes::array::<4>(bytes, 0).ok()? {
+        [b'C', b'I', b'S', b'O'] => match crate::bytes::u32(bytes, 4, crate::Endianness::Little).ok()? {
+            0 | 0x18 => Some(Format::Cso),
+            _ => Some(Format::Ciso),
+        },
+        [b'Z', b'I', b'S', b'O'] => Some(Format::Zso),
+        magic if u32::from_le_bytes(magic) == GCZ_MAGIC => Some(Format::Gcz),
+        _ => None,
+    }
+}
+
+pub fn is_container(bytes: &[u8]) -> bool {
+    format(bytes).is_some()
+}
+
+fn ciso_blocks(bytes: &[u8], block_size: usize) -> crate::Result<(Vec<Option<Block>>, usize)> {
+    let map = crate::bytes::slice(bytes, 8, CISO_HEADER_LENGTH - 8)?;
+    let block_count = map.iter().rposition(|present| *present != 0).map_or(0, |last| last + 1);
+
+    let mut offset = CISO_HEADER_LENGTH;
+    let mut blocks = Vec::with_capacity(block_count);
+    for present in &map[..block_count] {
+        if *present == 0 {
+            blocks.push(None);
+            continue;
+        }
+
+        blocks.push(Some(Block {
+            offset,
+            length: block_size,
+            compressed: false,
+        }));
+        offset += block_size;
+    }
+
+    Ok((blocks, block_count * block_size))
+}
+
+fn gcz_blocks(bytes: &[u8]) -> crate::Result<(Vec<Option<Block>>, usize, usize)> {
+    let compressed_length = crate::bytes::u64(bytes, 8, crate::Endianness::Little)? as usize;
+    let length = crate::bytes::u64(bytes, 16, crate::Endianness::Little)? as usize;
+    let block_size = crate::bytes::u32(bytes, 24, crate::Endianness::Little)? as usize;
+    let block_count = crate::bytes::u32(bytes, 28, crate::Endianness::Little)? as usize;
+
+    let pointers = crate::bytes::slice(bytes, 32, block_count.saturating_mul(8))?;
+    let pointers = (0..block_count)
+        .map(|i| crate::bytes::u64(pointers, i * 8, crate::Endianness::Little))
+        .collect::<crate::Result<Vec<_>>>()?;
+    let data_offset = 32 + block_count * 12;
+
+    let blocks = pointers
+        .iter()
+        .enumerate()
+        .map(|(i, pointer)| {
+            let start = (pointer & !(1 << 63)) as usize;
+            let end = pointers.get(i + 1).map_or(compressed_length, |next| (next & !(1 << 63)) as usize);
+            Some(Block {
+                offset: data_offset + start,
+                length: end.saturating_sub(start),
+                compressed: pointer & (1 << 63) == 0,
+            })
+        })
+        .collect();
+
+    Ok((blocks, length, block_size))
+}
+
+fn cso_blocks(bytes: &[u8]) -> crate::Result<(Vec<Option<Block>>, usize, usize)> {
+    let length = crate::bytes::u64(bytes, 8, crate::Endianness::Little)? as usize;
+    let block_size = crate::bytes::u32(bytes, 16, crate::Endianness::Little)? as usize;
+    let version = crate::bytes::u8(bytes, 20)?;
+    let align = crate::bytes::u8(bytes, 21)?;
+    if version > 1 {
+        return Err(crate::Error::InvalidDiscImage {
+            reason: "only version 1 CSO and ZSO images are supported",
+        });
+    }
+    if block_size == 0 || align > 31 {
+        return Err(crate::Error::InvalidDiscImage {
+            reason: "invalid block size or index alignment",
+        });
+    }
+
+    let block_count = length.div_ceil(block_size);
+    let index = crate::bytes::slice(bytes, CSO_HEADER_LENGTH, (block_count + 1).saturating_mul(4))?;
+    let index = (0..=block_count)
+        .map(|i| crate::bytes::u32(index, i * 4, crate::Endianness::Little))
+        .collect::<crate::Result<Vec<_>>>()?;
+
+    let blocks = index
+        .windows(2)
+        .map(|entries| {
+            let start = ((entries[0] & 0x7FFF_FFFF) as usize) << align;
+            let end = ((entries[1] & 0x7FFF_FFFF) as usize) << align;
+            Some(Block {
+                offset: start,
+                length: end.saturating_sub(start),
+                compressed: entries[0] & 0x8000_0000 == 0,
+            })
+        })
+        .collect();
+
+    Ok((blocks, length, block_size))
+}
+
+fn lz4(stored: &[u8], expected_length: usize, padding: usize) -> Result<Vec<u8>, String> {
+    // ZSO blocks are padded to the index alignment, and the padding is not part of the LZ4 block.
+    let mut decompressed = vec![0; expected_length];
+    let mut first_error = None;
+    for trimmed in 0..=padding.min(stored.len()) {
+        match lz4_flex::block::decompress_into(&stored[..stored.len() - trimmed], &mut decompressed) {
+            Ok(length) if length == expected_length => return Ok(decompressed),
+            Ok(length) => {
+                first_error.get_or_insert(format!("decompressed to {length} bytes instead of {expected_length}"))
+            }
+            Err(error) => first_error.get_or_insert(error.to_string()),
+        };
+    }
+
+    Err(first_error.unwrap_or_default())
+}
+
+fn inflate(mut decoder: impl Read, expected_length: usize) -> Result<Vec<u8>, String> {
+    let mut decompressed = Vec::with_capacity(expected_length);
+    decoder.read_to_end(&mut decompressed).map_err(|error| error.to_string())?;
+    Ok(decompressed)
+}
+
+impl<'a> Image<'a> {
+    pub fn open(bytes: &'a [u8]) -> crate::Result<Image<'a>> {
+        let format = format(bytes).ok_or(crate::Error::NotADiscImage)?;
+        let (blocks, length, block_size) = match format {
+            Format::Ciso => {
+                let block_size = crate::bytes::u32(bytes, 4, crate::Endianness::Little)? as usize;
+                let (blocks, length) = ciso_blocks(bytes, block_size)?;
+                (blocks, length, block_size)
+            }
+            Format::Gcz => gcz_blocks(bytes)?,
+            Format::Cso | Format::Zso => cso_blocks(bytes)?,
+        };
+        if block_size == 0 {
+            return Err(crate::Error::InvalidDiscImage {
+                reason: "block size is 0",
+            });
+        }
+
+        let padding = match format {
+            Format::Cso | Format::Zso => (1 << crate::bytes::u8(bytes, 21)?) - 1,
+            Format::Ciso | Format::Gcz => 0,
+        };
+
+        Ok(Image {
+            bytes,
+            format,
+            block_size,
+            length,
+            blocks,
+            padding,
+            cache: Default::default(),
+        })
+    }
+
+    fn decompress(&self, index: usize) -> crate::Result<Vec<u8>> {
+        let expected_length = (self.length - index * self.block_size).min(self.block_size);
+        let Some(Some(block)) = self.blocks.get(index) else {
+            return Ok(vec![0; expected_length]);
+        };
+
+        let stored = crate::disc::Image::read(&self.bytes, block.offset, block.length)?;
+        if !block.compressed {
+            return Ok(crate::bytes::slice(&stored, 0, expected_length)?.to_vec());
+        }
+
+        let decompressed = match self.format {
+            Format::Ciso => unreachable!("CISO blocks are stored uncompressed"),
+            Format::Gcz => inflate(flate2::read::ZlibDecoder::new(&stored[..]), expected_length),
+            Format::Cso => inflate(flate2::read::DeflateDecoder::new(&stored[..]), expected_length),
+            Format::Zso => lz4(&stored, expected_length, self.padding),
+        };
+        let mut decompressed = decompressed.map_err(|reason| crate::Error::CorruptBlock { index, reason })?;
+
+        if decompressed.len() < expected_length {
+            return Err(crate::Error::CorruptBlock {
+                index,
+                reason: format!(
+                    "decompressed to {} bytes instead of {expected_length}",
+                    decompressed.len()
+                ),
+            });
+        }
+
+        decompressed.truncate(expected_length);
+        Ok(decompressed)
+    }
+}
+
+impl crate::disc::Image for Image<'_> {
+    fn length(&self) -> usize {
+        self.length
+    }
+
+    fn read(&self, offset: usize, length: usize) -> crate::Result<std::borrow::Cow<'_, [u8]>> {
+        self.cache.read(offset, length, self.length, self.block_size, |index| {
+            self.decompress(index)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    const BLOCK_SIZE: usize = 0x800;
+
+    fn data() -> Vec<u8> {
+        let mut data = (0..BLOCK_SIZE * 3 + 0x123).map(|i| (i % 253) as u8).collect::<Vec<_>>();
+        data[BLOCK_SIZE..BLOCK_SIZE * 2].fill(0);
+        data
+    }
+
+    fn zlib(block: &[u8]) -> Vec<u8> {
+        let mut encoder = flate2::write::ZlibEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(block).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    fn deflate(block: &[u8]) -> Vec<u8> {
+        let mut encoder = flate2::write::DeflateEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(block).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    fn ciso(data: &[u8]) -> Vec<u8> {
+        let mut image = vec![0; super::CISO_HEADER_LENGTH];
+        image[..4].copy_from_slice(b"CISO");
+        image[4..8].copy_from_slice(&(BLOCK_SIZE as u32).to_le_bytes());
+        // Blocks of zeros are left out of the map, like in images made by the usual tools.
+        for (i, block) in data.chunks_exact(BLOCK_SIZE).enumerate() {
+            if block.iter().any(|byte| *byte != 0) {
+                image[8 + i] = 1;
+                image.extend_from_slice(block);
+            }
+        }
+        image
+    }
+
+    fn gcz(data: &[u8]) -> Vec<u8> {
+        let mut pointers = Vec::new();
+        let mut blocks = Vec::new();
+        for (i, block) in data.chunks(BLOCK_SIZE).enumerate() {
+            // The last block is stored uncompressed to cover both kinds.
+            let (stored, flag) = if i == 3 {
+                (block.to_vec(), 1 << 63)
+            } else {
+                (zlib(block), 0)
+            };
+            pointers.push(blocks.len() as u64 | flag);
+            blocks.extend_from_slice(&stored);
+        }
+
+        let mut image = vec![0; 32];
+        image[..4].copy_from_slice(&super::GCZ_MAGIC.to_le_bytes());
+        image[8..16].copy_from_slice(&(blocks.len() as u64).to_le_bytes());
+        image[16..24].copy_from_slice(&(data.len() as u64).to_le_bytes());
+        image[24..28].copy_from_slice(&(BLOCK_SIZE as u32).to_le_bytes());
+        image[28..32].copy_from_slice(&(pointers.len() as u32).to_le_bytes());
+        for pointer in &pointers {
+            image.extend_from_slice(&pointer.to_le_bytes());
+        }
+        image.resize(image.len() + pointers.len() * 4, 0);
+        image.extend_from_slice(&blocks);
+        image
+    }
+
+    fn cso(data: &[u8], magic: &[u8; 4], align: u8, compress: impl Fn(&[u8]) -> Vec<u8>) -> Vec<u8> {
+        let block_count = data.len().div_ceil(BLOCK_SIZE);
+        let mut image = vec![0; super::CSO_HEADER_LENGTH];
+        image[..4].copy_from_slice(magic);
+        image[4..8].copy_from_slice(&(super::CSO_HEADER_LENGTH as u32).to_le_bytes());
+        image[8..16].copy_from_slice(&(data.len() as u64).to_le_bytes());
+        image[16..20].copy_from_slice(&(BLOCK_SIZE as u32).to_le_bytes());
+        image[20] = 1;
+        image[21] = align;
+
+        let mut index = Vec::new();
+        let mut blocks = Vec::new();
+        let data_offset = (super::CSO_HEADER_LENGTH + (block_count + 1) * 4).next_multiple_of(1 << align);
+        for (i, block) in data.chunks(BLOCK_SIZE).enumerate() {
+            let (stored, flag) = if i == 0 {
+                (block.to_vec(), 0x8000_0000)
+            } else {
+                (compress(block), 0)
+            };
+            index.push(((data_offset + blocks.len()) >> align) as u32 | flag);
+            blocks.extend_from_slice(&stored);
+            blocks.resize(blocks.len().next_multiple_of(1 << align), 0);
+        }
+        index.push(((data_offset + blocks.len()) >> align) as u32);
+
+        for entry in index {
+            image.extend_from_slice(&entry.to_le_bytes());
+        }
+        image.resize(data_offset, 0);
+        image.extend_from_slice(&blocks);
+        image
+    }
+
+    fn check(image: &[u8], data: &[u8]) {
+        assert!(super::is_container(image));
+        let image = crate::disc::open(image, None).unwrap();
+        assert_eq!(image.length(), data.len());
+        assert_eq!(*image.read(0, data.len()).unwrap(), *data);
+        let start = BLOCK_SIZE * 2 - 7;
+        assert_eq!(*image.read(start, 0x20).unwrap(), data[start..start + 0x20]);
+    }
+
+    #[test]
+    fn ciso_round_trip() {
+        let mut data = data();
+        data.truncate(BLOCK_SIZE * 3);
+        check(&ciso(&data), &data);
+    }
+
+    #[test]
+    fn gcz_round_trip() {
+        let data = data();
+        check(&gcz(&data), &data);
+    }
+
+    #[test]
+    fn cso_round_trip() {
+        let data = data();
+        check(&cso(&data, b"CISO", 0, deflate), &data);
+        check(&cso(&data, b"CISO", 2, deflate), &data);
+    }
+
+    #[test]
+    fn zso_round_trip() {
+        let data = data();
+        check(&cso(&data, b"ZISO", 0, lz4_flex::block::compress), &data);
+        check(&cso(&data, b"ZISO", 4, lz4_flex::block::compress), &data);
+    }
+
+    #[test]
+    fn corrupt_block() {
+        let data = data();
+        let mut image = cso(&data, b"CISO", 0, deflate);
+        let length = image.len();
+        image[length - 0x40..].fill(0xFF);
+        let image = crate::disc::open(&image, None).unwrap();
+        assert!(matches!(
+            image.read(BLOCK_SIZE * 3, 0x10),
+            Err(crate::Error::CorruptBlock { index: 3, .. })
+        ));
+    }
+
+    #[test]
+    fn unsupported_cso_version() {
+        let mut image = cso(&data(), b"CISO", 0, deflate);
+        image[20] = 2;
+        assert!(matches!(
+            super::Image::open(&image),
+            Err(crate::Error::InvalidDiscImage { .. })
+        ));
+    }
+}
@@ -1,24 +1,54 @@
-pub fn list_textures(datasets_bytes: &[u8], endianness: crate::Endianness) -> Vec<(String, u32, &[u8])> {
-    let mut files = Vec::new();
-
+pub fn textures(
+    datasets_bytes: &[u8],
+    endianness: crate::Endianness,
+) -> impl Iterator<Item = crate::Result<crate::arc::Entry<'_>>> {
     let identifier = u32::from_le_bytes(*b"TXFL");
-    for (position, window) in datasets_bytes.windows(4).enumerate() {
-        if endianness.u32_from_bytes(window.try_into().unwrap()) == identifier {
-            let file_id = endianness.u32_from_bytes(datasets_bytes[position - 12..position - 8].try_into().unwrap());
-            let file_size =
-                endianness.u32_from_bytes(datasets_bytes[position - 8..position - 4].try_into().unwrap()) as usize;
-            let file_bytes = &datasets_bytes[position..position + file_size];
+    datasets_bytes
+        .windows(4)
+        .enumerate()
+        .filter(move |(_, window)| {
+            endianness.u32_from_bytes([window[0], window[1], window[2], window[3]]) == identifier
+        })
+        .map(move |(position, _)| texture(datasets_bytes, position, endianness))
+}
 
-            let null_position = file_bytes[4..].iter().position(|x| *x == 0).unwrap();
-            let name = std::str::from_utf8(&file_bytes[4..4 + null_position]).unwrap();
+fn texture(
+    datasets_bytes: &[u8],
+    position: usize,
+    endianness: crate::Endianness,
+) -> crate::Result<crate::arc::Entry<'_>> {
+    let header_position = position.checked_sub(12).ok_or(crate::Error::BadOffset {
+        offset: position,
+        length: 12,
+        available: datasets_bytes.len(),
+    })?;
+    let file_id = crate::bytes::u32(datasets_bytes, header_position, endianness)?;
+    let file_size = crate::bytes::u32(datasets_bytes, header_position + 4, endianness)? as usize;
+    let file_bytes = crate::bytes::slice(datasets_bytes, position, file_size).map_err(|_| crate::Error::BadOffset {
+        offset: position,
+        length: file_size,
+        available: datasets_bytes.len(),
+    })?;
 
-            files.push((
-                name.to_owned(),
-                file_id,
-                &datasets_bytes[position..position + file_size],
-            ));
-        }
-    }
+    let name = crate::bytes::name(file_bytes, 4).map_err(|error| match error {
+        crate::Error::InvalidName { offset } => crate::Error::InvalidName {
+            offset: position + offset,
+        },
+        error => error,
+    })?;
+
+    Ok(crate::arc::Entry {
+        name: name.to_owned(),
+        id: file_id,
+        offset: position,
+        bytes: file_bytes,
+        trailer: [0; 8],
+    })
+}
 
-    files
+pub fn list_textures(
+    datasets_bytes: &[u8],
+    endianness: crate::Endianness,
+) -> crate::Result<Vec<crate::arc::Entry<'_>>> {
+    textures(datasets_bytes, endianness).collect()
 }
@@ -0,0 +1,246 @@
+use crate::texture::{Format, TextureInfo};
+
+const DDSD_CAPS: u32 = 0x1;
+const DDSD_HEIGHT: u32 = 0x2;
+const DDSD_WIDTH: u32 = 0x4;
+const DDSD_PIXELFORMAT: u32 = 0x1000;
+const DDSD_MIPMAPCOUNT: u32 = 0x20000;
+const DDSD_LINEARSIZE: u32 = 0x80000;
+const DDPF_FOURCC: u32 = 0x4;
+const DDSCAPS_COMPLEX: u32 = 0x8;
+const DDSCAPS_TEXTURE: u32 = 0x1000;
+const DDSCAPS_MIPMAP: u32 = 0x400000;
+
+pub fn is_supported(format: Format) -> bool {
+    matches!(format, Format::XboxBc1 | Format::XboxBc2 | Format::GameCubeCmpr)
+}
+
+fn cmpr_to_bc1(bytes: &[u8], width: usize, height: usize) -> crate::Result<Vec<u8>> {
+    let bytes = crate::bytes::slice(bytes, 0, Format::GameCubeCmpr.level_length(width, height))?;
+
+    let block_columns = width.div_ceil(4);
+    let block_rows = height.div_ceil(4);
+    let tile_columns = width.div_ceil(8);
+
+    let mut blocks = Vec::new();
+    for block_y in 0..block_rows {
+        for block_x in 0..block_columns {
+            let tile = ((block_y / 2) * tile_columns) + (block_x / 2);
+            let sub_block = ((block_y % 2) * 2) + (block_x % 2);
+            let block = &bytes[((tile * 4) + sub_block) * 8..][..8];
+
+            blocks.extend_from_slice(&[block[1], block[0], block[3], block[2]]);
+            for row in &block[4..8] {
+                let mut reversed_row = 0;
+                for pixel in 0..4 {
+                    reversed_row |= ((row >> (pixel * 2)) & 0b11) << ((3 - pixel) * 2);
+                }
+                blocks.push(reversed_row);
+            }
+        }
+    }
+
+    Ok(blocks)
+}
+
+fn flip_block(block: &mut [u8], row_count: usize) {
+    let (alpha, color) = block.split_at_mut(block.len() - 8);
+
+    if !alpha.is_empty() {
+        let mut alpha_rows = alpha.chunks_exact(2).map(|row| [row[0], row[1]]).collect::<Vec<_>>();
+        alpha_rows[..row_count].reverse();
+        alpha.copy_from_slice(alpha_rows.as_flattened());
+    }
+
+    color[4..4 + row_count].reverse();
+}
+
+// The games store textures bottom row first, and every decoder flips them. DDS is top row first, so the block rows
+// are reversed and the pixel rows inside each block swapped. This is lossless, but the blocks are not byte for byte
+// the ones in the archive.
+fn flip_vertical(blocks: &[u8], format: Format, width: usize, height: usize) -> crate::Result<Vec<u8>> {
+    if height > 4 && !height.is_multiple_of(4) {
+        return Err(crate::Error::UnsupportedDimensions { width, height });
+    }
+
+    let block_length = if format == Format::XboxBc2 { 16 } else { 8 };
+    let row_length = width.div_ceil(4) * block_length;
+
+    let mut flipped = Vec::new();
+    for block_row in blocks.chunks_exact(row_length).rev() {
+        let mut block_row = block_row.to_vec();
+        for block in block_row.chunks_exact_mut(block_length) {
+            flip_block(block, height.min(4));
+        }
+        flipped.extend_from_slice(&block_row);
+    }
+
+    Ok(flipped)
+}
+
+fn level_blocks(bytes: &[u8], info: &TextureInfo) -> crate::Result<Vec<u8>> {
+    let data = crate::bytes::tail(bytes, info.data_offset)?;
+    let (blocks, format) = match info.format {
+        Format::GameCubeCmpr => (cmpr_to_bc1(data, info.width, info.height)?, Format::XboxBc1),
+        format => {
+            let length = format.level_length(info.width, info.height);
+            (crate::bytes::slice(data, 0, length)?.to_vec(), format)
+        }
+    };
+
+    flip_vertical(&blocks, format, info.width, info.height)
+}
+
+pub fn write(bytes: &[u8], info: &TextureInfo, mipmaps: &[TextureInfo]) -> crate::Result<Vec<u8>> {
+    if !is_supported(info.format) || info.rle_bit_count.is_some() {
+        return Err(crate::Error::UnsupportedDdsFormat {
+            format: info.format.name(),
+        });
+    }
+
+    let levels = std::iter::once(info).chain(mipmaps).collect::<Vec<_>>();
+
+    let mut data = Vec::new();
+    for level in &levels {
+        data.extend_from_slice(&level_blocks(bytes, level)?);
+    }
+
+    let (four_cc, block_length) = match info.format {
+        Format::XboxBc2 => (*b"DXT3", 16),
+        _ => (*b"DXT1", 8),
+    };
+    let linear_size = info.width.div_ceil(4) * info.height.div_ceil(4) * block_length;
+
+    let mut flags = DDSD_CAPS | DDSD_HEIGHT | DDSD_WIDTH | DDSD_PIXELFORMAT | DDSD_LINEARSIZE;
+    let mut caps = DDSCAPS_TEXTURE;
+    if levels.len() > 1 {
+        flags |= DDSD_MIPMAPCOUNT;
+        caps |= DDSCAPS_COMPLEX | DDSCAPS_MIPMAP;
+    }
+
+    let header: [u32; 31] = [
+        124,
+        flags,
+        info.height as u32,
+        info.width as u32,
+        linear_size as u32,
+        0,
+        levels.len() as u32,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        32,
+        DDPF_FOURCC,
+        u32::from_le_bytes(four_cc),
+        0,
+        0,
+        0,
+        0,
+        0,
+        caps,
+        0,
+        0,
+        0,
+        0,
+    ];
+
+    let mut dds = b"DDS ".to_vec();
+    for value in header {
+        dds.extend_from_slice(&value.to_le_bytes());
+    }
+    dds.extend_from_slice(&data);
+    Ok(dds)
+}
+
+#[cfg(test)]
+mod tests {
+    fn info(format: super::Format, width: usize, height: usize) -> super::TextureInfo {
+        super::TextureInfo {
+            texture_type: None,
+            format,
+            width,
+            height,
+            palette_count: 0,
+            data_offset: 0,
+            palette_offset: None,
+            rle_bit_count: None,
+        }
+    }
+
+    fn header_u32(dds: &[u8], offset: usize) -> u32 {
+        crate::bytes::u32(dds, offset, crate::Endianness::Little).unwrap()
+    }
+
+    fn decode_dds(dds: &[u8], format: texpresso::Format, width: usize, height: usize) -> Vec<u8> {
+        let mut pixels = vec![0; width * height * 4];
+        format.decompress(&dds[128..], width, height, &mut pixels);
+        pixels
+    }
+
+    fn pseudo_random_bytes(length: usize) -> Vec<u8> {
+        (0..length).map(|i| (i * 73 + 41) as u8 ^ (i >> 3) as u8).collect()
+    }
+
+    #[test]
+    fn header_with_mipmaps() {
+        let top = info(super::Format::XboxBc2, 8, 8);
+        let mut mipmap = info(super::Format::XboxBc2, 4, 4);
+        mipmap.data_offset = 64;
+        let dds = super::write(&[0; 80], &top, &[mipmap]).unwrap();
+
+        assert_eq!(&dds[..4], b"DDS ");
+        assert_eq!(dds.len(), 128 + 64 + 16);
+        assert_eq!(header_u32(&dds, 8) & super::DDSD_MIPMAPCOUNT, super::DDSD_MIPMAPCOUNT);
+        assert_eq!((header_u32(&dds, 12), header_u32(&dds, 16)), (8, 8));
+        assert_eq!(header_u32(&dds, 20), 64);
+        assert_eq!(header_u32(&dds, 28), 2);
+        assert_eq!(&dds[84..88], b"DXT3");
+    }
+
+    #[test]
+    fn block_rows_are_flipped() {
+        let bytes = [1, 2, 3, 4, 10, 11, 12, 13, 5, 6, 7, 8, 20, 21, 22, 23];
+        let dds = super::write(&bytes, &info(super::Format::XboxBc1, 4, 8), &[]).unwrap();
+        assert_eq!(dds[128..], [5, 6, 7, 8, 23, 22, 21, 20, 1, 2, 3, 4, 13, 12, 11, 10]);
+        assert_eq!(header_u32(&dds, 8) & super::DDSD_MIPMAPCOUNT, 0);
+    }
+
+    #[test]
+    fn bc1_matches_decoder() {
+        let bytes = pseudo_random_bytes(16 * 8 * 8 / 2);
+        let dds = super::write(&bytes, &info(super::Format::XboxBc1, 16, 8), &[]).unwrap();
+        let decoded = crate::xbox::decode_bc1(&bytes, 16, 8).unwrap();
+        assert_eq!(decode_dds(&dds, texpresso::Format::Bc1, 16, 8), decoded.into_raw());
+    }
+
+    #[test]
+    fn cmpr_converts_to_bc1() {
+        // Only the endpoint colors are used, as the two decoders round the interpolated colors differently.
+        let mut bytes = pseudo_random_bytes(16 * 8 / 2);
+        for (i, block) in bytes.chunks_exact_mut(8).enumerate() {
+            block[..2].copy_from_slice(&(0x8000 + i as u16 * 0x0421).to_be_bytes());
+            block[2..4].copy_from_slice(&(0x0100 + i as u16 * 0x0803).to_be_bytes());
+            for row in &mut block[4..] {
+                *row &= 0x55;
+            }
+        }
+        let dds = super::write(&bytes, &info(super::Format::GameCubeCmpr, 16, 8), &[]).unwrap();
+        let decoded = crate::gamecube::decode_cmpr(&bytes, 16, 8).unwrap();
+        assert_eq!(&dds[84..88], b"DXT1");
+        assert_eq!(decode_dds(&dds, texpresso::Format::Bc1, 16, 8), decoded.into_raw());
+    }
+
+    #[test]
+    fn unsupported_format() {
+        let result = super::write(&[0; 64], &info(super::Format::PlayStation2Rgba8, 4, 4), &[]);
+        assert!(matches!(result, Err(crate::Error::UnsupportedDdsFormat { .. })));
+    }
+}
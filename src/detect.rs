@@ -0,0 +1,602 @@
+use crate::{Game, Platform};
+
+// The RLE archives have no header to tell the platforms apart, so PlayStation 2 wins unless --platform is given, and
+// the detection reports the other platforms as ambiguous.
+const CANDIDATES: [(Game, Platform); 24] = [
+    (Game::TheSims, Platform::PlayStation2),
+    (Game::TheSims, Platform::GameCube),
+    (Game::TheSims, Platform::Xbox),
+    (Game::TheSimsRle, Platform::PlayStation2),
+    (Game::TheSimsRle, Platform::GameCube),
+    (Game::TheSimsRle, Platform::Xbox),
+    (Game::TheSimsBustinOut, Platform::PlayStation2),
+    (Game::TheSimsBustinOut, Platform::GameCube),
+    (Game::TheSimsBustinOut, Platform::Xbox),
+    (Game::TheUrbz, Platform::PlayStation2),
+    (Game::TheUrbz, Platform::GameCube),
+    (Game::TheUrbz, Platform::Xbox),
+    (Game::TheSims2, Platform::PlayStation2),
+    (Game::TheSims2, Platform::GameCube),
+    (Game::TheSims2, Platform::Xbox),
+    (Game::TheSims2Pets, Platform::PlayStation2),
+    (Game::TheSims2Pets, Platform::GameCube),
+    (Game::TheSims2Pets, Platform::Wii),
+    (Game::TheSims2Castaway, Platform::PlayStation2),
+    (Game::TheSims2Castaway, Platform::Wii),
+    (Game::TheSims3, Platform::Wii),
+    (Game::TheSimsBustinOutRle, Platform::PlayStation2),
+    (Game::TheSimsBustinOutRle, Platform::GameCube),
+    (Game::TheSimsBustinOutRle, Platform::Xbox),
+];
+
+const SAMPLE_SIZE: usize = 16;
+
+pub struct Detection {
+    pub game: Game,
+    pub platform: Platform,
+    pub endianness: crate::Endianness,
+    pub entry_count: usize,
+    pub sample_count: usize,
+    pub matched_count: usize,
+    pub ambiguous_platforms: Vec<Platform>,
+}
+
+impl std::fmt::Display for Detection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let endianness = match self.endianness {
+            crate::Endianness::Little => "little endian",
+            crate::Endianness::Big => "big endian",
+        };
+        write!(
+            f,
+            "{} ({}, {endianness}, {} entries, {} of {} sampled headers matched)",
+            self.game, self.platform, self.entry_count, self.matched_count, self.sample_count
+        )?;
+        if !self.ambiguous_platforms.is_empty() {
+            let platforms = self.ambiguous_platforms.iter().map(Platform::to_string).collect::<Vec<_>>();
+            write!(f, ", ambiguous with {}: pass --platform", platforms.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+fn roughness(image: &image::RgbaImage) -> f64 {
+    let mut total = 0u64;
+    let mut count = 0u64;
+    for row in image.rows() {
+        let row = row.collect::<Vec<_>>();
+        for pair in row.windows(2) {
+            for channel in 0..3 {
+                total += u64::from(pair[0].0[channel].abs_diff(pair[1].0[channel]));
+            }
+            count += 1;
+        }
+    }
+
+    if count == 0 {
+        0.0
+    } else {
+        total as f64 / count as f64
+    }
+}
+
+fn name_matches(entries: &[crate::arc::Entry], names: &std::collections::HashSet<&str>) -> usize {
+    entries.iter().filter(|entry| names.contains(&entry.name.as_str())).count()
+}
+
+fn name_offset(game: Game) -> Option<usize> {
+    match game {
+        Game::TheSims => Some(4),
+        Game::TheUrbz => Some(12),
+        Game::TheSims2 | Game::TheSims2Pets | Game::TheSims2Castaway | Game::TheSims3 => Some(16),
+        Game::TheSimsBustinOut => Some(20),
+        Game::TheSimsRle | Game::TheSimsBustinOutRle => None,
+    }
+}
+
+fn has_name(bytes: &[u8], offset: usize) -> bool {
+    crate::bytes::name(bytes, offset)
+        .is_ok_and(|name| !name.is_empty() && name.bytes().all(|byte| byte.is_ascii_graphic() || byte == b' '))
+}
+
+fn header_matches(entry: &crate::arc::Entry, game: Game, platform: Platform) -> bool {
+    if name_offset(game).is_some_and(|offset| !has_name(entry.bytes, offset)) {
+        return false;
+    }
+
+    let Ok(info) = crate::texture_info(entry.bytes, game, platform) else {
+        return false;
+    };
+    if info.width == 0 || info.height == 0 {
+        return false;
+    }
+
+    let Ok(data_length) = crate::texture::data_length(entry.bytes, &info) else {
+        return false;
+    };
+    match (info.format, info.rle_bit_count) {
+        (_, Some(_)) => {
+            crate::the_sims_3::rle_length(&entry.bytes[info.data_offset..]).is_ok_and(|length| length <= data_length)
+        }
+        (crate::texture::Format::RleC8 { .. }, None) => crate::rle_textures::decode_indices(entry.bytes).is_ok(),
+        // The Sims 2 Xbox headers have no type byte, so their data has to fill a mip chain exactly instead.
+        (_, None) if info.texture_type.is_none() => crate::texture::check_mip_chain(entry.bytes, &info).is_ok(),
+        (format, None) => format.level_length(info.width, info.height) <= data_length,
+    }
+}
+
+struct Candidate<'a> {
+    game: Game,
+    platform: Platform,
+    entry_count: usize,
+    sample: Vec<crate::arc::Entry<'a>>,
+    matched_count: usize,
+}
+
+fn candidate(archive: &[u8], game: Game, platform: Platform) -> Option<Candidate<'_>> {
+    let entries = crate::list_entries(archive, game, platform).ok()?;
+    if entries.is_empty() {
+        return None;
+    }
+
+    let entry_count = entries.len();
+    let step = entry_count.div_ceil(SAMPLE_SIZE);
+    let sample = entries.into_iter().step_by(step).collect::<Vec<_>>();
+    let matched_count = sample.iter().filter(|entry| header_matches(entry, game, platform)).count();
+
+    Some(Candidate {
+        game,
+        platform,
+        entry_count,
+        sample,
+        matched_count,
+    })
+}
+
+struct DecodeScore {
+    decoded_count: usize,
+    roughness: f64,
+    max_alpha: u8,
+}
+
+fn decode_score(candidate: &Candidate) -> DecodeScore {
+    let mut decoded_count = 0;
+    let mut total_roughness = 0.0;
+    let mut max_alpha = 0;
+    for entry in &candidate.sample {
+        let Ok(texture) = crate::decode_entry(entry, candidate.game, candidate.platform) else {
+            continue;
+        };
+
+        if texture.image.width() > 0 && texture.image.height() > 0 {
+            decoded_count += 1;
+            total_roughness += roughness(&texture.image);
+            max_alpha = texture.image.pixels().map(|pixel| pixel.0[3]).fold(max_alpha, u8::max);
+        }
+    }
+
+    DecodeScore {
+        decoded_count,
+        roughness: if decoded_count == 0 {
+            f64::MAX
+        } else {
+            total_roughness / decoded_count as f64
+        },
+        max_alpha,
+    }
+}
+
+fn refine_game(archive: &[u8], best: &Candidate, max_alpha: Option<u8>) -> (Game, Platform) {
+    match best.game {
+        Game::TheSimsRle | Game::TheSimsBustinOutRle => {
+            let game = match max_alpha {
+                Some(max_alpha) if max_alpha <= 128 => Game::TheSimsBustinOutRle,
+                Some(_) => Game::TheSimsRle,
+                None => best.game,
+            };
+            (game, best.platform)
+        }
+        Game::TheSims2 | Game::TheSims2Pets | Game::TheSims2Castaway | Game::TheSims3
+            if best.platform != Platform::Xbox =>
+        {
+            let Ok(entries) = crate::list_entries(archive, best.game, best.platform) else {
+                return (best.game, best.platform);
+            };
+
+            let mut candidates = vec![
+                (
+                    Game::TheSims2,
+                    name_matches(&entries, &crate::the_sims_2::SPECULAR_FILE_NAMES),
+                ),
+                (
+                    Game::TheSims2Pets,
+                    name_matches(&entries, &crate::the_sims_2_pets::SPECULAR_FILE_NAMES),
+                ),
+                (
+                    Game::TheSims2Castaway,
+                    name_matches(&entries, &crate::the_sims_2_castaway::SPECULAR_FILE_NAMES),
+                ),
+            ];
+            if best.game == Game::TheSims3 {
+                candidates.push((Game::TheSims3, usize::MAX));
+            }
+
+            let game = candidates
+                .into_iter()
+                .filter(|(game, _)| crate::endianness(*game, best.platform).is_ok())
+                .rev()
+                .max_by_key(|(_, matches)| *matches)
+                .map_or(best.game, |(game, _)| game);
+            (game, best.platform)
+        }
+        _ => (best.game, best.platform),
+    }
+}
+
+// Candidates are ranked by how many sampled headers match their layout (the name offset, the type byte and a data
+// length that fits), and the sampled textures are only decoded to choose between equally matching candidates.
+pub fn detect(archive: &[u8], game: Option<Game>, platform: Option<Platform>) -> Option<Detection> {
+    let mut candidates = Vec::new();
+    for (candidate_game, candidate_platform) in CANDIDATES {
+        if game.is_some_and(|game| game != candidate_game)
+            || platform.is_some_and(|platform| platform != candidate_platform)
+        {
+            continue;
+        }
+
+        if let Some(candidate) = candidate(archive, candidate_game, candidate_platform) {
+            if candidate.matched_count > 0 {
+                candidates.push(candidate);
+            }
+        }
+    }
+
+    let compare_matches =
+        |a: &Candidate, b: &Candidate| (a.matched_count * b.sample.len()).cmp(&(b.matched_count * a.sample.len()));
+    let top = candidates.iter().max_by(|a, b| compare_matches(a, b))?;
+    let tied = candidates.iter().filter(|candidate| compare_matches(candidate, top).is_eq()).collect::<Vec<_>>();
+
+    let mut ambiguous_platforms = Vec::new();
+    let (best, max_alpha) = if tied.len() == 1 {
+        (tied[0], None)
+    } else {
+        let scores = tied.iter().map(|candidate| decode_score(candidate)).collect::<Vec<_>>();
+        let max_decoded_count = scores.iter().map(|score| score.decoded_count).max().unwrap_or(0);
+
+        let mut best: Option<(&Candidate, &DecodeScore)> = None;
+        for (candidate, score) in tied.iter().zip(&scores) {
+            if score.decoded_count * 3 < max_decoded_count * 2 {
+                continue;
+            }
+
+            if best.is_none_or(|(_, best)| score.roughness < best.roughness) {
+                best = Some((candidate, score));
+            }
+        }
+
+        let (best, score) = best?;
+
+        // Candidates with the same layout decode to the same images, so only --platform can choose between them.
+        for (candidate, other_score) in tied.iter().zip(&scores) {
+            if candidate.platform != best.platform
+                && other_score.decoded_count == score.decoded_count
+                && other_score.roughness == score.roughness
+                && !ambiguous_platforms.contains(&candidate.platform)
+            {
+                ambiguous_platforms.push(candidate.platform);
+            }
+        }
+
+        (best, Some(score.max_alpha))
+    };
+
+    let (detected_game, detected_platform) = match game {
+        Some(game) => (game, best.platform),
+        None => refine_game(archive, best, max_alpha),
+    };
+
+    Some(Detection {
+        game: detected_game,
+        platform: detected_platform,
+        endianness: crate::endianness(detected_game, detected_platform).ok()?,
+        entry_count: best.entry_count,
+        sample_count: best.sample.len(),
+        matched_count: best.matched_count,
+        ambiguous_platforms,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Endianness, Game, Platform};
+
+    fn gradient(width: u32, height: u32, alpha: u8) -> image::RgbaImage {
+        image::RgbaImage::from_fn(width, height, |x, y| {
+            image::Rgba([(x * 256 / width) as u8, (y * 256 / height) as u8, 128, alpha])
+        })
+    }
+
+    fn texture(
+        game: Game,
+        platform: Platform,
+        prefix: &[u8],
+        name: &str,
+        header_length: usize,
+        fields: &[(usize, &[u8])],
+        data_length: usize,
+    ) -> Vec<u8> {
+        let mut header = vec![0; header_length];
+        for (offset, field) in fields {
+            header[*offset..offset + field.len()].copy_from_slice(field);
+        }
+
+        let mut bytes = [prefix, name.as_bytes(), &header].concat();
+        bytes.resize(bytes.len() + data_length, 0);
+        let info = crate::texture_info(&bytes, game, platform).unwrap();
+        crate::texture::encode(&bytes, &info, &gradient(16, 16, 255)).unwrap()
+    }
+
+    fn rle_texture(game: Game, platform: Platform, alpha: u8) -> Vec<u8> {
+        let bytes = vec![0; (256 * 4) + 5];
+        let info = crate::texture_info(&bytes, game, platform).unwrap();
+        let image = image::RgbaImage::from_fn(256, 256, |x, _| {
+            let value = (x / 16 * 16) as u8;
+            image::Rgba([value, value, value, alpha])
+        });
+        crate::texture::encode(&bytes, &info, &image).unwrap()
+    }
+
+    fn arc(name: &str, bytes: &[u8], endianness: Endianness) -> Vec<u8> {
+        let entry = crate::arc::Entry {
+            name: name.to_owned(),
+            id: 1,
+            offset: 0,
+            bytes,
+            trailer: [0; 8],
+        };
+        crate::arc::write_files(&[entry], endianness).unwrap()
+    }
+
+    fn datasets(texture: &[u8], endianness: Endianness) -> Vec<u8> {
+        [
+            &endianness.u32_to_bytes(7)[..],
+            &endianness.u32_to_bytes(texture.len() as u32),
+            &[0; 4],
+            texture,
+        ]
+        .concat()
+    }
+
+    fn fixture(game: Game, platform: Platform) -> Vec<u8> {
+        let (little, big) = (Endianness::Little, Endianness::Big);
+        let size = |endianness: Endianness| match endianness {
+            Endianness::Little => 16u16.to_le_bytes(),
+            Endianness::Big => 16u16.to_be_bytes(),
+        };
+        let (le, be) = (size(little), size(big));
+        let sims_2_name = match game {
+            Game::TheSims2Pets => "forest_mountain_catbuttes",
+            Game::TheSims2Castaway => "boar_default",
+            Game::TheSims3 => "treepear_bark",
+            _ => "condo_concrete_a",
+        };
+
+        match (game, platform) {
+            (Game::TheSims, Platform::PlayStation2) => datasets(
+                &texture(
+                    game,
+                    platform,
+                    b"TXFL",
+                    "floor",
+                    21,
+                    &[(3, &le), (5, &le), (7, &[0])],
+                    1024,
+                ),
+                little,
+            ),
+            (Game::TheSims, Platform::GameCube) => datasets(
+                &texture(
+                    game,
+                    platform,
+                    b"LFXT",
+                    "floor",
+                    21,
+                    &[(3, &be), (5, &be), (7, &[0])],
+                    128,
+                ),
+                big,
+            ),
+            (Game::TheSims, Platform::Xbox) => datasets(
+                &texture(
+                    game,
+                    platform,
+                    b"TXFL",
+                    "floor",
+                    21,
+                    &[(3, &le), (5, &le), (7, &[0])],
+                    1024,
+                ),
+                little,
+            ),
+            (Game::TheSimsRle, Platform::GameCube) => arc("rle", &rle_texture(game, platform, 255), big),
+            (Game::TheSimsRle, _) => arc("rle", &rle_texture(game, platform, 255), little),
+            (Game::TheSimsBustinOutRle, Platform::GameCube) => arc("rle", &rle_texture(game, platform, 200), big),
+            (Game::TheSimsBustinOutRle, _) => arc("rle", &rle_texture(game, platform, 200), little),
+            (Game::TheSimsBustinOut, Platform::PlayStation2) => arc(
+                "wall",
+                &texture(
+                    game,
+                    platform,
+                    &[0; 20],
+                    "wall",
+                    21,
+                    &[(3, &le), (5, &le), (7, &[0])],
+                    1024,
+                ),
+                little,
+            ),
+            (Game::TheSimsBustinOut, Platform::GameCube) => arc(
+                "wall",
+                &texture(
+                    game,
+                    platform,
+                    &[0; 20],
+                    "wall",
+                    21,
+                    &[(1, &[0x81]), (3, &be), (5, &be)],
+                    128,
+                ),
+                big,
+            ),
+            (Game::TheSimsBustinOut, Platform::Xbox) => arc(
+                "wall",
+                &texture(
+                    game,
+                    platform,
+                    &[0; 20],
+                    "wall",
+                    21,
+                    &[(1, &[0x8E]), (3, &le), (5, &le)],
+                    128,
+                ),
+                little,
+            ),
+            (Game::TheUrbz, Platform::PlayStation2) => arc(
+                "building_25",
+                &texture(
+                    game,
+                    platform,
+                    &[0; 12],
+                    "building_25",
+                    33,
+                    &[(17, &le), (19, &le), (25, &[1]), (27, &[32])],
+                    1024,
+                ),
+                little,
+            ),
+            (Game::TheUrbz, Platform::GameCube) => arc(
+                "building_25",
+                &texture(
+                    game,
+                    platform,
+                    &[0; 12],
+                    "building_25",
+                    33,
+                    &[(17, &be), (19, &be), (25, &[0x81])],
+                    128,
+                ),
+                big,
+            ),
+            (Game::TheUrbz, Platform::Xbox) => arc(
+                "building_25",
+                &texture(
+                    game,
+                    platform,
+                    &[0; 12],
+                    "building_25",
+                    33,
+                    &[(17, &le), (19, &le), (25, &[0x8E])],
+                    128,
+                ),
+                little,
+            ),
+            (_, Platform::PlayStation2) => arc(
+                sims_2_name,
+                &texture(
+                    game,
+                    platform,
+                    &[0; 16],
+                    sims_2_name,
+                    37,
+                    &[(21, &le), (23, &le), (30, &[0]), (31, &[32])],
+                    1024,
+                ),
+                little,
+            ),
+            (Game::TheSims2, Platform::Xbox) => arc(
+                sims_2_name,
+                &texture(game, platform, &[0; 16], sims_2_name, 37, &[(21, &le), (23, &le)], 128),
+                little,
+            ),
+            (Game::TheSims3, _) => arc(
+                sims_2_name,
+                &texture(
+                    game,
+                    platform,
+                    &[0; 16],
+                    sims_2_name,
+                    37,
+                    &[(21, &be), (23, &be), (29, &[0x85]), (31, &[32]), (32, &[32])],
+                    4,
+                ),
+                big,
+            ),
+            _ => arc(
+                sims_2_name,
+                &texture(
+                    game,
+                    platform,
+                    &[0; 16],
+                    sims_2_name,
+                    37,
+                    &[(21, &be), (23, &be), (29, &[0x81])],
+                    128,
+                ),
+                big,
+            ),
+        }
+    }
+
+    #[test]
+    fn detects_every_candidate() {
+        for (game, platform) in super::CANDIDATES {
+            // These layouts are identical to another platform's, so only the platform hint tells them apart.
+            let hint = match (game, platform) {
+                (Game::TheSimsRle | Game::TheSimsBustinOutRle, Platform::Xbox) => Some(platform),
+                (Game::TheSims2Pets | Game::TheSims2Castaway, Platform::Wii) => Some(platform),
+                _ => None,
+            };
+
+            let archive = fixture(game, platform);
+            let detection =
+                super::detect(&archive, None, hint).unwrap_or_else(|| panic!("{game} ({platform}) was not detected"));
+            assert_eq!(
+                (detection.game, detection.platform),
+                (game, platform),
+                "{game} ({platform}) was detected as {detection}"
+            );
+            assert_eq!(detection.matched_count, 1);
+        }
+    }
+
+    #[test]
+    fn identical_layouts_are_ambiguous() {
+        let archive = fixture(Game::TheSims2Pets, Platform::Wii);
+        let detection = super::detect(&archive, None, None).unwrap();
+        assert_eq!(detection.platform, Platform::GameCube);
+        assert_eq!(detection.ambiguous_platforms, [Platform::Wii]);
+        assert!(detection.to_string().ends_with("ambiguous with Wii: pass --platform"));
+
+        let detection = super::detect(&archive, None, Some(Platform::Wii)).unwrap();
+        assert_eq!(
+            (detection.game, detection.platform),
+            (Game::TheSims2Pets, Platform::Wii)
+        );
+        assert!(detection.ambiguous_platforms.is_empty());
+
+        let archive = fixture(Game::TheUrbz, Platform::GameCube);
+        assert!(super::detect(&archive, None, None).unwrap().ambiguous_platforms.is_empty());
+    }
+
+    #[test]
+    fn header_mismatch_is_not_detected() {
+        let archive = arc("garbage", &[0xFF; 64], Endianness::Little);
+        assert!(super::detect(&archive, None, None).is_none());
+    }
+
+    #[test]
+    fn roughness_of_flat_image_is_zero() {
+        assert_eq!(super::roughness(&image::RgbaImage::new(4, 4)), 0.0);
+        assert!(super::roughness(&gradient(4, 4, 255)) > 0.0);
+    }
+}
@@ -0,0 +1,248 @@
+pub trait Image {
+    fn length(&self) -> usize;
+    fn read(&self, offset: usize, length: usize) -> crate::Result<std::borrow::Cow<'_, [u8]>>;
+}
+
+impl Image for &[u8] {
+    fn length(&self) -> usize {
+        self.len()
+    }
+
+    fn read(&self, offset: usize, length: usize) -> crate::Result<std::borrow::Cow<'_, [u8]>> {
+        crate::bytes::slice(self, offset, length)
+            .map(std::borrow::Cow::Borrowed)
+            .map_err(|_| crate::Error::BadOffset {
+                offset,
+                length,
+                available: self.len(),
+            })
+    }
+}
+
+#[derive(Default)]
+pub struct BlockCache(std::cell::RefCell<Option<(usize, Vec<u8>)>>);
+
+impl BlockCache {
+    pub fn read(
+        &self,
+        offset: usize,
+        length: usize,
+        image_length: usize,
+        block_size: usize,
+        mut read_block: impl FnMut(usize) -> crate::Result<Vec<u8>>,
+    ) -> crate::Result<std::borrow::Cow<'_, [u8]>> {
+        if offset.checked_add(length).is_none_or(|end| end > image_length) {
+            return Err(crate::Error::BadOffset {
+                offset,
+                length,
+                available: image_length,
+            });
+        }
+
+        let mut bytes = Vec::with_capacity(length);
+        let mut cache = self.0.borrow_mut();
+        while bytes.len() < length {
+            let position = offset + bytes.len();
+            let index = position / block_size;
+            if cache.as_ref().is_none_or(|(cached_index, _)| *cached_index != index) {
+                *cache = Some((index, read_block(index)?));
+            }
+
+            let block = &cache.as_ref().expect("block was just cached").1;
+            let start = position % block_size;
+            let end = block.len().min(start + length - bytes.len());
+            bytes.extend_from_slice(&block[start..end]);
+        }
+
+        Ok(std::borrow::Cow::Owned(bytes))
+    }
+}
+
+pub struct File {
+    pub path: String,
+    pub offset: usize,
+    pub length: usize,
+}
+
+impl File {
+    pub fn new(image: &dyn Image, path: String, offset: usize, length: usize) -> crate::Result<File> {
+        if offset.checked_add(length).is_none_or(|end| end > image.length()) {
+            return Err(crate::Error::BadOffset {
+                offset,
+                length,
+                available: image.length(),
+            });
+        }
+
+        Ok(File { path, offset, length })
+    }
+}
+
+pub fn open<'a>(bytes: &'a [u8], common_key: Option<&[u8; 16]>) -> crate::Result<Box<dyn Image + 'a>> {
+    let image: Box<dyn Image + 'a> = if crate::compressed::is_container(bytes) {
+        Box::new(crate::compressed::Image::open(bytes)?)
+    } else {
+        Box::new(bytes)
+    };
+    if !crate::wii::is_image(image.as_ref())? {
+        return Ok(image);
+    }
+
+    let common_key = common_key.ok_or(crate::Error::MissingCommonKey)?;
+    Ok(Box::new(crate::wii::Partition::open(image, common_key)?))
+}
+
+pub fn has_signature(image: &dyn Image, offset: usize, signature: &[u8]) -> crate::Result<bool> {
+    if offset + signature.len() > image.length() {
+        return Ok(false);
+    }
+
+    Ok(*image.read(offset, signature.len())? == *signature)
+}
+
+pub fn is_image(image: &dyn Image) -> crate::Result<bool> {
+    Ok(crate::iso9660::is_image(image)?
+        || crate::gcm::is_image(image)?
+        || crate::wii::is_image(image)?
+        || crate::xdvdfs::is_image(image)?)
+}
+
+pub fn is_disc(bytes: &[u8]) -> crate::Result<bool> {
+    Ok(crate::compressed::is_container(bytes) || is_image(&bytes)?)
+}
+
+pub fn files(image: &dyn Image) -> crate::Result<Vec<File>> {
+    let mut files = if crate::gcm::is_image(image)? {
+        crate::gcm::files(image, 0)?
+    } else if crate::wii::is_image(image)? {
+        crate::gcm::files(image, 2)?
+    } else if crate::xdvdfs::is_image(image)? {
+        crate::xdvdfs::files(image)?
+    } else if crate::iso9660::is_image(image)? {
+        crate::iso9660::files(image)?
+    } else {
+        return Err(crate::Error::NotADiscImage);
+    };
+
+    files.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(files)
+}
+
+pub fn archive_names(game: Option<crate::Game>) -> &'static [&'static str] {
+    match game {
+        Some(crate::Game::TheSims) => &["datasets.arc"],
+        Some(crate::Game::TheSimsRle | crate::Game::TheSimsBustinOutRle) => &["rletextu.arc", "rletextures.arc"],
+        Some(_) => &["textures.arc"],
+        None => &["textures.arc", "datasets.arc", "rletextu.arc", "rletextures.arc"],
+    }
+}
+
+fn file_name(path: &str) -> &str {
+    path.rsplit('/').next().unwrap_or(path)
+}
+
+pub fn is_archive(path: &str) -> bool {
+    file_name(path).to_ascii_lowercase().ends_with(".arc")
+}
+
+pub fn find(files: Vec<File>, path: &str) -> crate::Result<File> {
+    let path = format!("/{}", path.trim_start_matches(['/', '\\']).replace('\\', "/"));
+    files
+        .into_iter()
+        .find(|file| file.path.eq_ignore_ascii_case(&path))
+        .ok_or(crate::Error::FileNotFound { path })
+}
+
+pub fn find_archive(mut files: Vec<File>, game: Option<crate::Game>) -> crate::Result<File> {
+    let names = archive_names(game);
+    let position = names
+        .iter()
+        .find_map(|name| files.iter().position(|file| file_name(&file.path).eq_ignore_ascii_case(name)));
+    match position {
+        Some(position) => Ok(files.swap_remove(position)),
+        None => Err(crate::Error::NoArchiveFound { names }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    fn files(paths: &[&str]) -> Vec<super::File> {
+        paths
+            .iter()
+            .enumerate()
+            .map(|(i, path)| super::File {
+                path: path.to_string(),
+                offset: i,
+                length: 1,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn find_by_path() {
+        let paths = ["/TEXTURES/TEXTURES.ARC", "/SYSTEM.CNF"];
+        assert_eq!(super::find(files(&paths), "textures\\textures.arc").unwrap().offset, 0);
+        assert_eq!(super::find(files(&paths), "/system.cnf").unwrap().offset, 1);
+        assert!(matches!(
+            super::find(files(&paths), "TEXTURES.ARC"),
+            Err(crate::Error::FileNotFound { ref path }) if path == "/TEXTURES.ARC"
+        ));
+    }
+
+    #[test]
+    fn find_archive_by_name() {
+        let paths = ["/data/rletextu.arc", "/data/Textures.arc", "/data/datasets.arc"];
+        assert_eq!(
+            super::find_archive(files(&paths), None).unwrap().path,
+            "/data/Textures.arc"
+        );
+        let game = Some(crate::Game::TheSims);
+        assert_eq!(
+            super::find_archive(files(&paths), game).unwrap().path,
+            "/data/datasets.arc"
+        );
+        let game = Some(crate::Game::TheSimsRle);
+        assert_eq!(
+            super::find_archive(files(&paths), game).unwrap().path,
+            "/data/rletextu.arc"
+        );
+        assert!(matches!(
+            super::find_archive(files(&paths[..1]), Some(crate::Game::TheUrbz)),
+            Err(crate::Error::NoArchiveFound { .. })
+        ));
+    }
+
+    #[test]
+    fn archive_paths() {
+        assert!(super::is_archive("/data/UI.ARC"));
+        assert!(!super::is_archive("/arc/readme.txt"));
+    }
+
+    #[test]
+    fn plain_archive_is_not_a_disc() {
+        let bytes = vec![0; 0x10000];
+        assert!(!super::is_disc(&bytes).unwrap());
+        assert!(matches!(
+            super::files(super::open(&bytes, None).unwrap().as_ref()),
+            Err(crate::Error::NotADiscImage)
+        ));
+    }
+
+    #[test]
+    fn block_cache_reads_across_blocks() {
+        let cache = super::BlockCache::default();
+        let mut reads = Vec::new();
+        let bytes = cache
+            .read(3, 6, 10, 4, |index| {
+                reads.push(index);
+                Ok((index * 4..(index * 4 + 4).min(10)).map(|i| i as u8).collect())
+            })
+            .unwrap();
+        assert_eq!(*bytes, [3, 4, 5, 6, 7, 8]);
+        assert_eq!(reads, [0, 1, 2]);
+        assert!(matches!(
+            cache.read(8, 4, 10, 4, |_| unreachable!()),
+            Err(crate::Error::BadOffset { .. })
+        ));
+    }
+}
@@ -0,0 +1,276 @@
+#[derive(Debug)]
+pub enum Error {
+    UnknownTextureType {
+        texture_type: u8,
+    },
+    UnsupportedPaletteCount {
+        palette_count: usize,
+    },
+    UnsupportedBitCount {
+        bit_count: u8,
+    },
+    UnsupportedDimensions {
+        width: usize,
+        height: usize,
+    },
+    UnsupportedPlatform {
+        game: crate::Game,
+        platform: crate::Platform,
+    },
+    UnrecognizedArchive,
+    UnrecognizedEndianness,
+    Truncated {
+        offset: usize,
+        length: usize,
+        available: usize,
+    },
+    BadOffset {
+        offset: usize,
+        length: usize,
+        available: usize,
+    },
+    InvalidName {
+        offset: usize,
+    },
+    DimensionMismatch {
+        expected_width: usize,
+        expected_height: usize,
+        width: usize,
+        height: usize,
+    },
+    TooManyColors {
+        color_count: usize,
+        palette_count: usize,
+    },
+    MipChainMismatch {
+        level_count: usize,
+        chain_length: usize,
+        data_length: usize,
+    },
+    UnsupportedDdsFormat {
+        format: &'static str,
+    },
+    InvalidTextureFlag {
+        line: usize,
+        text: String,
+    },
+    InvalidTextureId {
+        line: usize,
+        text: String,
+    },
+    EntryNotFound {
+        name: String,
+    },
+    ArchiveTooLarge {
+        length: usize,
+    },
+    EntrySizeChanged {
+        length: usize,
+        encoded_length: usize,
+    },
+    InvalidDiscImage {
+        reason: &'static str,
+    },
+    NotADiscImage,
+    DiscImageNotWritable,
+    MissingCommonKey,
+    InvalidCommonKey {
+        path: std::path::PathBuf,
+    },
+    WrongCommonKey,
+    CorruptBlock {
+        index: usize,
+        reason: String,
+    },
+    FileNotFound {
+        path: String,
+    },
+    NoArchiveFound {
+        names: &'static [&'static str],
+    },
+    RepackMismatch {
+        offset: usize,
+        length: usize,
+        repacked_length: usize,
+    },
+    Io {
+        path: std::path::PathBuf,
+        source: std::io::Error,
+    },
+    Image {
+        path: std::path::PathBuf,
+        source: image::ImageError,
+    },
+    Png {
+        path: std::path::PathBuf,
+        source: png::EncodingError,
+    },
+    ThreadPool {
+        source: rayon::ThreadPoolBuildError,
+    },
+    Entry {
+        name: String,
+        id: u32,
+        offset: usize,
+        source: Box<Error>,
+    },
+    Archive {
+        path: std::path::PathBuf,
+        source: Box<Error>,
+    },
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Error {
+    pub fn in_entry(self, entry: &crate::arc::Entry) -> Error {
+        Error::Entry {
+            name: entry.name.clone(),
+            id: entry.id,
+            offset: entry.offset,
+            source: Box::new(self),
+        }
+    }
+
+    pub fn in_archive(self, path: &std::path::Path) -> Error {
+        Error::Archive {
+            path: path.to_owned(),
+            source: Box::new(self),
+        }
+    }
+
+    pub fn io(path: &std::path::Path, source: std::io::Error) -> Error {
+        Error::Io {
+            path: path.to_owned(),
+            source,
+        }
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::UnknownTextureType { texture_type } => write!(f, "unknown texture type {texture_type:#04X}"),
+            Error::UnsupportedPaletteCount { palette_count } => {
+                write!(f, "unsupported palette count {palette_count}")
+            }
+            Error::UnsupportedBitCount { bit_count } => write!(f, "unsupported bit count {bit_count}"),
+            Error::UnsupportedDimensions { width, height } => write!(f, "unsupported dimensions {width}x{height}"),
+            Error::UnsupportedPlatform { game, platform } => write!(f, "{game} is not available on {platform}"),
+            Error::UnrecognizedArchive => write!(f, "could not detect the game and platform of the archive"),
+            Error::UnrecognizedEndianness => {
+                write!(
+                    f,
+                    "could not read the entry list of the archive as little or big endian"
+                )
+            }
+            Error::Truncated {
+                offset,
+                length,
+                available,
+            } => write!(
+                f,
+                "truncated data: needed {length} bytes at offset {offset:#X} but only {available} bytes are available"
+            ),
+            Error::BadOffset {
+                offset,
+                length,
+                available,
+            } => write!(
+                f,
+                "bad offset: {length} bytes at offset {offset:#X} lie outside the {available} available bytes"
+            ),
+            Error::InvalidName { offset } => write!(f, "name at offset {offset:#X} is not valid UTF-8"),
+            Error::DimensionMismatch {
+                expected_width,
+                expected_height,
+                width,
+                height,
+            } => write!(
+                f,
+                "image is {width}x{height} but the texture is {expected_width}x{expected_height}"
+            ),
+            Error::TooManyColors {
+                color_count,
+                palette_count,
+            } => write!(
+                f,
+                "image has {color_count} colors but the texture palette only holds {palette_count}"
+            ),
+            Error::MipChainMismatch {
+                level_count,
+                chain_length,
+                data_length,
+            } => write!(
+                f,
+                "texture data is {data_length} bytes but {level_count} mip levels take {chain_length} bytes"
+            ),
+            Error::UnsupportedDdsFormat { format } => write!(f, "{format} textures cannot be written to DDS"),
+            Error::InvalidTextureFlag { line, text } => write!(
+                f,
+                "line {line}: expected \"specular <name>\", \"alpha <name>\", \"specular id:<id>\" or \"alpha id:<id>\" but found \"{text}\""
+            ),
+            Error::InvalidTextureId { line, text } => write!(f, "line {line}: expected a texture id but found \"{text}\""),
+            Error::EntryNotFound { name } => write!(f, "no entry named \"{name}\""),
+            Error::ArchiveTooLarge { length } => {
+                write!(f, "archive of {length} bytes does not fit in 32-bit offsets")
+            }
+            Error::EntrySizeChanged {
+                length,
+                encoded_length,
+            } => write!(
+                f,
+                "re-encoded texture is {encoded_length} bytes instead of {length}, and datasets.arc textures cannot change size"
+            ),
+            Error::InvalidDiscImage { reason } => write!(f, "invalid disc image: {reason}"),
+            Error::NotADiscImage => write!(f, "not a recognized disc image"),
+            Error::DiscImageNotWritable => {
+                write!(f, "disc images are not supported, replace the texture in the unpacked archive instead")
+            }
+            Error::MissingCommonKey => {
+                write!(f, "Wii disc partitions are encrypted, add --common-key with a file holding the Wii common key")
+            }
+            Error::InvalidCommonKey { path } => {
+                write!(f, "{}: expected a common key of 16 bytes or 32 hex digits", path.display())
+            }
+            Error::WrongCommonKey => write!(f, "the data partition did not decrypt to a valid header, check the common key"),
+            Error::CorruptBlock { index, reason } => write!(f, "compressed block {index} is corrupt: {reason}"),
+            Error::FileNotFound { path } => write!(f, "no file \"{path}\" in the disc image"),
+            Error::NoArchiveFound { names } => {
+                write!(f, "none of {} was found in the disc image", names.join(", "))
+            }
+            Error::RepackMismatch {
+                offset,
+                length,
+                repacked_length,
+            } => write!(
+                f,
+                "repacked archive ({repacked_length} bytes) differs from the original ({length} bytes) at offset {offset:#X}"
+            ),
+            Error::Io { path, source } => write!(f, "{}: {source}", path.display()),
+            Error::Image { path, source } => write!(f, "{}: {source}", path.display()),
+            Error::Png { path, source } => write!(f, "{}: {source}", path.display()),
+            Error::ThreadPool { source } => write!(f, "failed to start worker threads: {source}"),
+            Error::Entry {
+                name,
+                id,
+                offset,
+                source,
+            } => write!(f, "entry \"{name}\" (id {id}, offset {offset:#X}): {source}"),
+            Error::Archive { path, source } => write!(f, "{}: {source}", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            Error::Image { source, .. } => Some(source),
+            Error::Png { source, .. } => Some(source),
+            Error::ThreadPool { source } => Some(source),
+            Error::Entry { source, .. } | Error::Archive { source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,149 @@
+#[derive(Clone, Default)]
+pub struct Filter {
+    pub name_globs: Vec<glob::Pattern>,
+    pub name_regexes: Vec<regex::Regex>,
+    pub ids: Vec<u32>,
+    pub texture_types: Vec<u8>,
+    pub formats: Vec<String>,
+    pub min_width: Option<usize>,
+    pub min_height: Option<usize>,
+    pub max_width: Option<usize>,
+    pub max_height: Option<usize>,
+}
+
+impl Filter {
+    pub fn needs_texture_info(&self) -> bool {
+        !self.texture_types.is_empty()
+            || !self.formats.is_empty()
+            || self.min_width.is_some()
+            || self.min_height.is_some()
+            || self.max_width.is_some()
+            || self.max_height.is_some()
+    }
+
+    fn matches_name(&self, name: &str) -> bool {
+        (self.name_globs.is_empty() && self.name_regexes.is_empty())
+            || self.name_globs.iter().any(|pattern| pattern.matches(name))
+            || self.name_regexes.iter().any(|regex| regex.is_match(name))
+    }
+
+    fn matches_texture_info(&self, info: &crate::texture::TextureInfo) -> bool {
+        let texture_type_matches = self.texture_types.is_empty()
+            || info.texture_type.is_some_and(|texture_type| self.texture_types.contains(&texture_type));
+        let format_matches = self.formats.is_empty()
+            || self.formats.iter().any(|format| format.eq_ignore_ascii_case(info.format.name()));
+
+        texture_type_matches
+            && format_matches
+            && self.min_width.is_none_or(|min_width| info.width >= min_width)
+            && self.min_height.is_none_or(|min_height| info.height >= min_height)
+            && self.max_width.is_none_or(|max_width| info.width <= max_width)
+            && self.max_height.is_none_or(|max_height| info.height <= max_height)
+    }
+
+    fn matches_name_and_id(&self, entry: &crate::arc::Entry) -> bool {
+        self.matches_name(&entry.name) && (self.ids.is_empty() || self.ids.contains(&entry.id))
+    }
+
+    pub fn matches(&self, entry: &crate::arc::Entry, game: crate::Game, platform: crate::Platform) -> bool {
+        if !self.matches_name_and_id(entry) {
+            return false;
+        }
+
+        if !self.needs_texture_info() {
+            return true;
+        }
+
+        crate::texture_info(entry.bytes, game, platform).is_ok_and(|info| self.matches_texture_info(&info))
+    }
+
+    // Without a game and platform the texture headers cannot be read, so only the name and id filters are checked.
+    pub fn matches_raw(
+        &self,
+        entry: &crate::arc::Entry,
+        game_and_platform: Option<(crate::Game, crate::Platform)>,
+    ) -> bool {
+        match game_and_platform {
+            Some((game, platform)) => self.matches(entry, game, platform),
+            None => self.matches_name_and_id(entry),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Game, Platform};
+
+    fn texture_bytes(width: u16, height: u16) -> Vec<u8> {
+        let mut header = vec![0; 37];
+        header[21..23].copy_from_slice(&width.to_le_bytes());
+        header[23..25].copy_from_slice(&height.to_le_bytes());
+        header[31] = 32;
+        let mut bytes = [&[0; 16][..], b"wall", &header].concat();
+        bytes.resize(bytes.len() + width as usize * height as usize * 4, 0);
+        bytes
+    }
+
+    fn entry<'a>(name: &str, id: u32, bytes: &'a [u8]) -> crate::arc::Entry<'a> {
+        crate::arc::Entry {
+            name: name.to_owned(),
+            id,
+            offset: 0,
+            bytes,
+            trailer: [0; 8],
+        }
+    }
+
+    #[test]
+    fn name_and_id() {
+        let filter = super::Filter {
+            name_globs: vec![glob::Pattern::new("wall_*").unwrap()],
+            name_regexes: vec![regex::Regex::new("^floor[0-9]$").unwrap()],
+            ids: vec![1, 2],
+            ..Default::default()
+        };
+
+        assert!(filter.matches_raw(&entry("wall_a", 1, &[]), None));
+        assert!(filter.matches_raw(&entry("floor2", 2, &[]), None));
+        assert!(!filter.matches_raw(&entry("floor22", 2, &[]), None));
+        assert!(!filter.matches_raw(&entry("wall_a", 3, &[]), None));
+        assert!(super::Filter::default().matches_raw(&entry("anything", 9, &[]), None));
+    }
+
+    #[test]
+    fn texture_info() {
+        let bytes = texture_bytes(16, 8);
+        let entry = entry("wall", 1, &bytes);
+        let game_and_platform = Some((Game::TheSims2, Platform::PlayStation2));
+        let filter = |filter: super::Filter| filter.matches_raw(&entry, game_and_platform);
+
+        assert!(filter(super::Filter {
+            formats: vec!["rgba8".to_owned()],
+            texture_types: vec![0],
+            min_width: Some(16),
+            max_height: Some(8),
+            ..Default::default()
+        }));
+        assert!(!filter(super::Filter {
+            formats: vec!["C8".to_owned()],
+            ..Default::default()
+        }));
+        assert!(!filter(super::Filter {
+            min_height: Some(9),
+            ..Default::default()
+        }));
+        assert!(!filter(super::Filter {
+            texture_types: vec![2],
+            ..Default::default()
+        }));
+    }
+
+    #[test]
+    fn unreadable_header_does_not_match() {
+        let filter = super::Filter {
+            max_width: Some(64),
+            ..Default::default()
+        };
+        assert!(!filter.matches(&entry("wall", 1, &[0; 8]), Game::TheSims2, Platform::PlayStation2));
+    }
+}
@@ -1,68 +1,185 @@
-pub fn decode_cmpr(bytes: &[u8], width: usize, height: usize) -> image::RgbaImage {
-    let decompressed_data = gctex::decode(bytes, width as _, height as _, gctex::TextureFormat::CMPR, &[], 0);
-    let image = image::RgbaImage::from_raw(width as _, height as _, decompressed_data.to_vec()).unwrap();
-    image::imageops::flip_vertical(&image)
+fn check_size(bytes: &[u8], format: gctex::TextureFormat, width: usize, height: usize) -> crate::Result<&[u8]> {
+    crate::bytes::slice(
+        bytes,
+        0,
+        gctex::compute_image_size(format, width as _, height as _) as usize,
+    )
 }
 
-pub fn decode_rgb5a3(bytes: &[u8], width: usize, height: usize) -> image::RgbaImage {
-    let decompressed_data = gctex::decode(bytes, width as _, height as _, gctex::TextureFormat::RGB5A3, &[], 0);
-    let image = image::RgbaImage::from_raw(width as _, height as _, decompressed_data.to_vec()).unwrap();
-    image::imageops::flip_vertical(&image)
+fn decode(bytes: &[u8], format: gctex::TextureFormat, width: usize, height: usize) -> crate::Result<image::RgbaImage> {
+    let bytes = check_size(bytes, format, width, height)?;
+    let decompressed_data = gctex::decode(bytes, width as _, height as _, format, &[], 0);
+    let mut image = image::RgbaImage::from_raw(width as _, height as _, decompressed_data).unwrap();
+    image::imageops::flip_vertical_in_place(&mut image);
+    Ok(image)
 }
 
-pub fn decode_rgba8(bytes: &[u8], width: usize, height: usize) -> image::RgbaImage {
-    let decompressed_data = gctex::decode(bytes, width as _, height as _, gctex::TextureFormat::RGBA8, &[], 0);
+pub fn decode_cmpr(bytes: &[u8], width: usize, height: usize) -> crate::Result<image::RgbaImage> {
+    decode(bytes, gctex::TextureFormat::CMPR, width, height)
+}
+
+pub fn decode_rgb5a3(bytes: &[u8], width: usize, height: usize) -> crate::Result<image::RgbaImage> {
+    decode(bytes, gctex::TextureFormat::RGB5A3, width, height)
+}
+
+pub fn decode_rgba8(bytes: &[u8], width: usize, height: usize) -> crate::Result<image::RgbaImage> {
+    decode(bytes, gctex::TextureFormat::RGBA8, width, height)
+}
 
-    let image = image::RgbaImage::from_raw(width as _, height as _, decompressed_data.to_vec()).unwrap();
-    image::imageops::flip_vertical(&image)
+pub fn decode_palette(palette: &[u8], palette_count: usize) -> crate::Result<Vec<[u8; 4]>> {
+    let palette = crate::bytes::slice(palette, 0, palette_count * 4)?;
+    let (green_red, alpha_blue) = palette.split_at(palette_count * 2);
+    Ok(green_red
+        .chunks_exact(2)
+        .zip(alpha_blue.chunks_exact(2))
+        .map(|(green_red, alpha_blue)| [green_red[1], green_red[0], alpha_blue[1], alpha_blue[0]])
+        .collect())
 }
 
-pub fn decode_c4(bytes: &[u8], width: usize, height: usize, palette: &[u8]) -> image::RgbaImage {
+pub fn decode_c4_indices(bytes: &[u8], width: usize, height: usize) -> crate::Result<image::GrayImage> {
+    let bytes = check_size(bytes, gctex::TextureFormat::I4, width, height)?;
     let decompressed_data = gctex::decode(bytes, width as _, height as _, gctex::TextureFormat::I4, &[], 0);
 
-    let mut deswizzled_palette = Vec::new();
-    for i in 0..16 {
-        deswizzled_palette.push(palette[(i * 2) + 1]);
-        deswizzled_palette.push(palette[i * 2]);
-        deswizzled_palette.push(palette[(i * 2) + 32 + 1]);
-        deswizzled_palette.push(palette[(i * 2) + 32]);
+    let indices = crate::bytes::rows_bottom_up(&decompressed_data, width * 4)
+        .flat_map(|row| row.iter().step_by(4).map(|pixel| pixel / 16))
+        .collect();
+    Ok(image::GrayImage::from_raw(width as _, height as _, indices).unwrap())
+}
+
+pub fn decode_c8_indices(bytes: &[u8], width: usize, height: usize) -> crate::Result<image::GrayImage> {
+    let bytes = check_size(bytes, gctex::TextureFormat::I8, width, height)?;
+    let decompressed_data = gctex::decode(bytes, width as _, height as _, gctex::TextureFormat::I8, &[], 1);
+
+    let indices = crate::bytes::rows_bottom_up(&decompressed_data, width * 4)
+        .flat_map(|row| row.iter().step_by(4).copied())
+        .collect();
+    Ok(image::GrayImage::from_raw(width as _, height as _, indices).unwrap())
+}
+
+pub fn decode_c4(bytes: &[u8], width: usize, height: usize, palette: &[u8]) -> crate::Result<image::RgbaImage> {
+    let indices = decode_c4_indices(bytes, width, height)?;
+    let palette = decode_palette(palette, 16)?;
+    Ok(crate::texture::expand(&indices, &palette))
+}
+
+pub fn decode_c8(bytes: &[u8], width: usize, height: usize, palette: &[u8]) -> crate::Result<image::RgbaImage> {
+    let indices = decode_c8_indices(bytes, width, height)?;
+    let palette = decode_palette(palette, 256)?;
+    Ok(crate::texture::expand(&indices, &palette))
+}
+
+fn encode(image: &image::RgbaImage, format: gctex::TextureFormat) -> Vec<u8> {
+    let image = image::imageops::flip_vertical(image);
+    gctex::encode(format, image.as_raw(), image.width(), image.height())
+}
+
+pub fn encode_cmpr(image: &image::RgbaImage) -> Vec<u8> {
+    encode(image, gctex::TextureFormat::CMPR)
+}
+
+pub fn encode_rgb5a3(image: &image::RgbaImage) -> Vec<u8> {
+    encode(image, gctex::TextureFormat::RGB5A3)
+}
+
+pub fn encode_rgba8(image: &image::RgbaImage) -> Vec<u8> {
+    encode(image, gctex::TextureFormat::RGBA8)
+}
+
+fn tile(indices: &image::GrayImage, block_width: u32, block_height: u32) -> Vec<u8> {
+    let indices = image::imageops::flip_vertical(indices);
+    let (width, height) = indices.dimensions();
+
+    let mut tiled = Vec::new();
+    for block_y in (0..height).step_by(block_height as usize) {
+        for block_x in (0..width).step_by(block_width as usize) {
+            for y in block_y..block_y + block_height {
+                for x in block_x..block_x + block_width {
+                    tiled.push(indices.get_pixel(x.min(width - 1), y.min(height - 1)).0[0]);
+                }
+            }
+        }
     }
 
-    let mut pixels = Vec::new();
-    for pixel in decompressed_data[0..].iter().step_by(4) {
-        pixels.push(deswizzled_palette[(usize::from(*pixel) / 16) * 4]);
-        pixels.push(deswizzled_palette[(usize::from(*pixel) / 16) * 4 + 1]);
-        pixels.push(deswizzled_palette[(usize::from(*pixel) / 16) * 4 + 2]);
-        pixels.push(deswizzled_palette[(usize::from(*pixel) / 16) * 4 + 3]);
+    tiled
+}
+
+pub fn encode_c4(indices: &image::GrayImage) -> Vec<u8> {
+    tile(indices, 8, 8).chunks_exact(2).map(|pair| (pair[0] << 4) | (pair[1] & 0b0000_1111)).collect()
+}
+
+pub fn encode_c8(indices: &image::GrayImage) -> Vec<u8> {
+    tile(indices, 8, 4)
+}
+
+pub fn encode_palette(colors: &[[u8; 4]], palette_count: usize) -> Vec<u8> {
+    let mut palette = vec![0; palette_count * 4];
+    for (i, [r, g, b, a]) in colors.iter().enumerate() {
+        palette[i * 2..(i * 2) + 2].copy_from_slice(&[*g, *r]);
+        palette[(i * 2) + (palette_count * 2)..(i * 2) + (palette_count * 2) + 2].copy_from_slice(&[*a, *b]);
     }
 
-    let image = image::RgbaImage::from_raw(width as _, height as _, pixels).unwrap();
-    image::imageops::flip_vertical(&image)
+    palette
 }
 
-pub fn decode_c8(bytes: &[u8], width: usize, height: usize, palette: &[u8]) -> image::RgbaImage {
-    let mut decompressed_data = gctex::decode(bytes, width as _, height as _, gctex::TextureFormat::I8, &[], 1);
+#[cfg(test)]
+mod tests {
+    fn image(width: u32, height: u32) -> image::RgbaImage {
+        image::RgbaImage::from_fn(width, height, |x, y| {
+            image::Rgba([(x * 16) as u8, (y * 16) as u8, ((x + y) * 8) as u8, (x * 32) as u8])
+        })
+    }
+
+    fn indices(width: u32, height: u32, count: u32) -> image::GrayImage {
+        image::GrayImage::from_fn(width, height, |x, y| image::Luma([((x + y * width) % count) as u8]))
+    }
+
+    #[test]
+    fn rgba8_round_trip() {
+        let image = image(8, 8);
+        assert_eq!(super::decode_rgba8(&super::encode_rgba8(&image), 8, 8).unwrap(), image);
+    }
 
-    for a in decompressed_data[3..].iter_mut().step_by(4) {
-        *a = 255;
+    #[test]
+    fn rgb5a3_round_trip() {
+        let image = image::RgbaImage::from_fn(8, 8, |x, y| match (x + y) % 3 {
+            0 => image::Rgba([255, 0, 255, 255]),
+            1 => image::Rgba([0, 255, 0, 255]),
+            _ => image::Rgba([0, 0, 0, 0]),
+        });
+        assert_eq!(
+            super::decode_rgb5a3(&super::encode_rgb5a3(&image), 8, 8).unwrap(),
+            image
+        );
     }
 
-    let mut deswizzled_palette = Vec::new();
-    for i in 0..256 {
-        deswizzled_palette.push(palette[(i * 2) + 1]);
-        deswizzled_palette.push(palette[i * 2]);
-        deswizzled_palette.push(palette[(i * 2) + 512 + 1]);
-        deswizzled_palette.push(palette[(i * 2) + 512]);
+    #[test]
+    fn cmpr_keeps_solid_blocks() {
+        let image = image::RgbaImage::from_fn(8, 8, |_, y| {
+            if y < 4 {
+                image::Rgba([255, 0, 0, 255])
+            } else {
+                image::Rgba([0, 0, 255, 255])
+            }
+        });
+        let bytes = super::encode_cmpr(&image);
+        assert_eq!(bytes.len(), 32);
+        assert_eq!(super::decode_cmpr(&bytes, 8, 8).unwrap(), image);
     }
 
-    let mut pixels = Vec::new();
-    for pixel in decompressed_data[0..].iter().step_by(4) {
-        pixels.push(deswizzled_palette[usize::from(*pixel) * 4]);
-        pixels.push(deswizzled_palette[usize::from(*pixel) * 4 + 1]);
-        pixels.push(deswizzled_palette[usize::from(*pixel) * 4 + 2]);
-        pixels.push(deswizzled_palette[usize::from(*pixel) * 4 + 3]);
+    #[test]
+    fn indices_round_trip() {
+        let c4 = indices(8, 8, 16);
+        assert_eq!(super::decode_c4_indices(&super::encode_c4(&c4), 8, 8).unwrap(), c4);
+        let c8 = indices(16, 8, 256);
+        assert_eq!(super::decode_c8_indices(&super::encode_c8(&c8), 16, 8).unwrap(), c8);
     }
 
-    let image = image::RgbaImage::from_raw(width as _, height as _, pixels).unwrap();
-    image::imageops::flip_vertical(&image)
+    #[test]
+    fn palette_round_trip() {
+        let colors = [[1, 2, 3, 4], [5, 6, 7, 8]];
+        let palette = super::encode_palette(&colors, 16);
+        assert_eq!(&palette[..4], [2, 1, 6, 5]);
+        assert_eq!(&palette[32..36], [4, 3, 8, 7]);
+        assert_eq!(&super::decode_palette(&palette, 16).unwrap()[..2], colors);
+    }
 }
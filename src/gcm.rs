@@ -0,0 +1,129 @@
+const MAGIC_OFFSET: usize = 0x1C;
+const MAGIC: u32 = 0xC2339F3D;
+
+pub struct FstEntry {
+    pub path: String,
+    pub offset: usize,
+    pub length: usize,
+}
+
+pub fn is_image(image: &dyn crate::disc::Image) -> crate::Result<bool> {
+    crate::disc::has_signature(image, MAGIC_OFFSET, &MAGIC.to_be_bytes())
+}
+
+fn fst_name(string_table: &[u8], offset: usize) -> crate::Result<String> {
+    let length = crate::bytes::null_position(string_table, offset)?;
+    Ok(String::from_utf8_lossy(&string_table[offset..offset + length]).into_owned())
+}
+
+pub fn fst(fst: &[u8], offset_shift: u32) -> crate::Result<Vec<FstEntry>> {
+    let entry_count = crate::bytes::u32(fst, 8, crate::Endianness::Big)? as usize;
+    let string_table = crate::bytes::tail(fst, entry_count.saturating_mul(12))?;
+
+    let mut entries = Vec::new();
+    let mut directories: Vec<(usize, String)> = Vec::new();
+    for i in 1..entry_count {
+        while directories.last().is_some_and(|(end, _)| *end <= i) {
+            directories.pop();
+        }
+
+        let entry = crate::bytes::slice(fst, i * 12, 12)?;
+        let flags_and_name = crate::bytes::u32(entry, 0, crate::Endianness::Big)?;
+        let name = fst_name(string_table, (flags_and_name & 0x00FF_FFFF) as usize)?;
+        let parent_path = directories.last().map_or("", |(_, path)| path.as_str());
+        let path = format!("{parent_path}/{name}");
+
+        if flags_and_name >> 24 != 0 {
+            let end = crate::bytes::u32(entry, 8, crate::Endianness::Big)? as usize;
+            directories.push((end, path));
+            continue;
+        }
+
+        let offset = (crate::bytes::u32(entry, 4, crate::Endianness::Big)? as usize) << offset_shift;
+        let length = crate::bytes::u32(entry, 8, crate::Endianness::Big)? as usize;
+        entries.push(FstEntry { path, offset, length });
+    }
+
+    Ok(entries)
+}
+
+pub fn files(image: &dyn crate::disc::Image, offset_shift: u32) -> crate::Result<Vec<crate::disc::File>> {
+    let header = image.read(0x424, 8)?;
+    let fst_offset = (crate::bytes::u32(&header, 0, crate::Endianness::Big)? as usize) << offset_shift;
+    let fst_length = (crate::bytes::u32(&header, 4, crate::Endianness::Big)? as usize) << offset_shift;
+    let fst_bytes = image.read(fst_offset, fst_length)?;
+
+    fst(&fst_bytes, offset_shift)?
+        .into_iter()
+        .map(|entry| crate::disc::File::new(image, entry.path, entry.offset, entry.length))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    fn entry(directory: bool, name_offset: u32, offset: u32, length: u32) -> Vec<u8> {
+        let flags = if directory { 1 << 24 } else { 0 };
+        [
+            (flags | name_offset).to_be_bytes(),
+            offset.to_be_bytes(),
+            length.to_be_bytes(),
+        ]
+        .concat()
+    }
+
+    fn fst() -> Vec<u8> {
+        [
+            entry(true, 0, 0, 5),
+            entry(true, 0, 0, 4),
+            entry(true, 5, 1, 4),
+            entry(false, 14, 0x100, 6),
+            entry(false, 27, 0x200, 4),
+            b"data\0textures\0Textures.arc\0opening.bnr\0".to_vec(),
+        ]
+        .concat()
+    }
+
+    #[test]
+    fn fst_paths() {
+        let entries = super::fst(&fst(), 0).unwrap();
+        let entries = entries.iter().map(|entry| (entry.path.as_str(), entry.offset, entry.length)).collect::<Vec<_>>();
+        assert_eq!(
+            entries,
+            [("/data/textures/Textures.arc", 0x100, 6), ("/opening.bnr", 0x200, 4)]
+        );
+    }
+
+    #[test]
+    fn fst_offset_shift() {
+        let entries = super::fst(&fst(), 2).unwrap();
+        assert_eq!(entries[0].offset, 0x400);
+    }
+
+    #[test]
+    fn truncated_fst() {
+        let mut fst = fst();
+        fst[8..12].copy_from_slice(&50u32.to_be_bytes());
+        assert!(matches!(super::fst(&fst, 0), Err(crate::Error::Truncated { .. })));
+    }
+
+    #[test]
+    fn files() {
+        let mut image = vec![0; 0x800];
+        image[super::MAGIC_OFFSET..super::MAGIC_OFFSET + 4].copy_from_slice(&super::MAGIC.to_be_bytes());
+        let fst = fst();
+        image[0x424..0x428].copy_from_slice(&0x500u32.to_be_bytes());
+        image[0x428..0x42C].copy_from_slice(&(fst.len() as u32).to_be_bytes());
+        image[0x500..0x500 + fst.len()].copy_from_slice(&fst);
+
+        assert!(super::is_image(&image.as_slice()).unwrap());
+        let files = super::files(&image.as_slice(), 0).unwrap();
+        assert_eq!(files.len(), 2);
+        assert_eq!(files[0].path, "/data/textures/Textures.arc");
+
+        image[0x500 + 3 * 12 + 8..0x500 + 4 * 12].copy_from_slice(&0x1000u32.to_be_bytes());
+        assert!(matches!(
+            super::files(&image.as_slice(), 0),
+            Err(crate::Error::BadOffset { .. })
+        ));
+    }
+}
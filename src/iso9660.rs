@@ -0,0 +1,198 @@
+const VOLUME_DESCRIPTOR_OFFSET: usize = 16 * 2048;
+
+pub fn is_image(image: &dyn crate::disc::Image) -> crate::Result<bool> {
+    crate::disc::has_signature(image, VOLUME_DESCRIPTOR_OFFSET + 1, b"CD001")
+}
+
+fn primary_volume_descriptor(image: &dyn crate::disc::Image) -> crate::Result<std::borrow::Cow<'_, [u8]>> {
+    let mut offset = VOLUME_DESCRIPTOR_OFFSET;
+    loop {
+        let descriptor = image.read(offset, 2048)?;
+        if &descriptor[1..6] != b"CD001" {
+            return Err(crate::Error::InvalidDiscImage {
+                reason: "volume descriptor signature is missing",
+            });
+        }
+
+        match descriptor[0] {
+            1 => return Ok(descriptor),
+            255 => {
+                return Err(crate::Error::InvalidDiscImage {
+                    reason: "no primary volume descriptor",
+                })
+            }
+            _ => offset += 2048,
+        }
+    }
+}
+
+struct Record {
+    name: String,
+    directory: bool,
+    offset: usize,
+    length: usize,
+}
+
+fn record(bytes: &[u8], block_size: usize) -> crate::Result<Record> {
+    let extent = crate::bytes::u32(bytes, 2, crate::Endianness::Little)? as usize;
+    let length = crate::bytes::u32(bytes, 10, crate::Endianness::Little)? as usize;
+    let flags = crate::bytes::u8(bytes, 25)?;
+    let name_length = usize::from(crate::bytes::u8(bytes, 32)?);
+    let name = crate::bytes::slice(bytes, 33, name_length)?;
+
+    let name = String::from_utf8_lossy(name);
+    let name = name.split(';').next().unwrap_or_default().trim_end_matches('.');
+
+    Ok(Record {
+        name: name.to_owned(),
+        directory: flags & 0b0000_0010 != 0,
+        offset: extent * block_size,
+        length,
+    })
+}
+
+fn directory(image: &dyn crate::disc::Image, directory: &Record, block_size: usize) -> crate::Result<Vec<Record>> {
+    let bytes = image.read(directory.offset, directory.length)?;
+
+    let mut records = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        let record_length = usize::from(bytes[i]);
+        if record_length == 0 {
+            i = (i / block_size + 1) * block_size;
+            continue;
+        }
+
+        let record_bytes = crate::bytes::slice(&bytes, i, record_length)?;
+        let name_length = crate::bytes::u8(record_bytes, 32)?;
+        let is_self_or_parent = name_length == 1 && matches!(crate::bytes::u8(record_bytes, 33)?, 0 | 1);
+        if !is_self_or_parent {
+            records.push(record(record_bytes, block_size)?);
+        }
+
+        i += record_length;
+    }
+
+    Ok(records)
+}
+
+pub fn files(image: &dyn crate::disc::Image) -> crate::Result<Vec<crate::disc::File>> {
+    let descriptor = primary_volume_descriptor(image)?;
+    let block_size = usize::from(crate::bytes::u16(&descriptor, 128, crate::Endianness::Little)?);
+    if block_size == 0 {
+        return Err(crate::Error::InvalidDiscImage {
+            reason: "logical block size is 0",
+        });
+    }
+
+    let root = record(&descriptor[156..190], block_size)?;
+
+    let mut files = Vec::new();
+    let mut visited = std::collections::HashSet::from([root.offset]);
+    let mut pending = vec![(String::new(), root)];
+    while let Some((path, parent)) = pending.pop() {
+        for record in directory(image, &parent, block_size)? {
+            let path = format!("{path}/{}", record.name);
+            if record.directory {
+                if visited.insert(record.offset) {
+                    pending.push((path, record));
+                }
+                continue;
+            }
+
+            files.push(crate::disc::File::new(image, path, record.offset, record.length)?);
+        }
+    }
+
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    fn record(name: &[u8], directory: bool, sector: u32, length: u32) -> Vec<u8> {
+        let mut record = vec![0; (33 + name.len()).next_multiple_of(2)];
+        record[0] = record.len() as u8;
+        record[2..6].copy_from_slice(&sector.to_le_bytes());
+        record[10..14].copy_from_slice(&length.to_le_bytes());
+        record[25] = if directory { 0b0000_0010 } else { 0 };
+        record[32] = name.len() as u8;
+        record[33..33 + name.len()].copy_from_slice(name);
+        record
+    }
+
+    fn directory(sector: u32, parent: u32, records: &[Vec<u8>]) -> Vec<u8> {
+        [
+            record(&[0], true, sector, 2048),
+            record(&[1], true, parent, 2048),
+            records.concat(),
+        ]
+        .concat()
+    }
+
+    fn write(image: &mut [u8], sector: usize, bytes: &[u8]) {
+        image[sector * 2048..sector * 2048 + bytes.len()].copy_from_slice(bytes);
+    }
+
+    fn image() -> Vec<u8> {
+        let mut image = vec![0; 22 * 2048];
+        let mut descriptor = vec![0; 2048];
+        descriptor[..7].copy_from_slice(b"\x01CD001\x01");
+        descriptor[128..130].copy_from_slice(&2048u16.to_le_bytes());
+        descriptor[156..190].copy_from_slice(&record(&[0], true, 18, 2048));
+        write(&mut image, 16, &descriptor);
+        write(&mut image, 17, b"\xFFCD001\x01");
+
+        let root = directory(
+            18,
+            18,
+            &[
+                record(b"SYSTEM.CNF;1", false, 21, 4),
+                record(b"TEXTURES", true, 19, 2048),
+                record(b"LOOP", true, 18, 2048),
+            ],
+        );
+        write(&mut image, 18, &root);
+        write(
+            &mut image,
+            19,
+            &directory(19, 18, &[record(b"TEXTURES.ARC;1", false, 20, 6)]),
+        );
+        write(&mut image, 20, b"arcarc");
+        write(&mut image, 21, b"BOOT");
+        image
+    }
+
+    #[test]
+    fn files() {
+        let image = image();
+        assert!(super::is_image(&image.as_slice()).unwrap());
+
+        let mut files = super::files(&image.as_slice()).unwrap();
+        files.sort_by(|a, b| a.path.cmp(&b.path));
+        let files = files.iter().map(|file| (file.path.as_str(), file.offset, file.length)).collect::<Vec<_>>();
+        assert_eq!(
+            files,
+            [("/SYSTEM.CNF", 21 * 2048, 4), ("/TEXTURES/TEXTURES.ARC", 20 * 2048, 6)]
+        );
+    }
+
+    #[test]
+    fn missing_primary_volume_descriptor() {
+        let mut image = image();
+        image[16 * 2048] = 255;
+        assert!(matches!(
+            super::files(&image.as_slice()),
+            Err(crate::Error::InvalidDiscImage { .. })
+        ));
+    }
+
+    #[test]
+    fn file_past_the_end() {
+        let mut image = image();
+        image.truncate(21 * 2048);
+        assert!(matches!(
+            super::files(&image.as_slice()),
+            Err(crate::Error::BadOffset { .. })
+        ));
+    }
+}
@@ -0,0 +1,1038 @@
+pub mod arc;
+mod bytes;
+pub mod compressed;
+pub mod datasets;
+pub mod dds;
+pub mod detect;
+pub mod disc;
+pub mod error;
+pub mod filter;
+pub mod gamecube;
+pub mod gcm;
+pub mod iso9660;
+pub mod metadata;
+pub mod palette;
+pub mod playstation_2;
+pub mod rle_textures;
+pub mod texture;
+pub mod texture_flags;
+pub mod the_sims;
+pub mod the_sims_2;
+pub mod the_sims_2_castaway;
+pub mod the_sims_2_pets;
+pub mod the_sims_3;
+pub mod the_sims_bustin_out;
+pub mod the_urbz;
+pub mod wii;
+pub mod xbox;
+pub mod xdvdfs;
+
+pub use error::{Error, Result};
+
+#[derive(Clone, Copy)]
+pub enum Endianness {
+    Little,
+    Big,
+}
+
+impl Endianness {
+    pub fn u16_from_bytes(&self, bytes: [u8; 2]) -> u16 {
+        match self {
+            Endianness::Little => u16::from_le_bytes(bytes),
+            Endianness::Big => u16::from_be_bytes(bytes),
+        }
+    }
+
+    pub fn u32_from_bytes(&self, bytes: [u8; 4]) -> u32 {
+        match self {
+            Endianness::Little => u32::from_le_bytes(bytes),
+            Endianness::Big => u32::from_be_bytes(bytes),
+        }
+    }
+
+    pub fn u64_from_bytes(&self, bytes: [u8; 8]) -> u64 {
+        match self {
+            Endianness::Little => u64::from_le_bytes(bytes),
+            Endianness::Big => u64::from_be_bytes(bytes),
+        }
+    }
+
+    pub fn u32_to_bytes(&self, value: u32) -> [u8; 4] {
+        match self {
+            Endianness::Little => value.to_le_bytes(),
+            Endianness::Big => value.to_be_bytes(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Game {
+    TheSims,
+    TheSimsRle,
+    TheSimsBustinOut,
+    TheSimsBustinOutRle,
+    TheUrbz,
+    TheSims2,
+    TheSims2Pets,
+    TheSims2Castaway,
+    TheSims3,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Platform {
+    PlayStation2,
+    GameCube,
+    Xbox,
+    Wii,
+}
+
+impl std::fmt::Display for Game {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Game::TheSims => "The Sims",
+            Game::TheSimsRle => "The Sims (RLE textures)",
+            Game::TheSimsBustinOut => "The Sims Bustin' Out",
+            Game::TheSimsBustinOutRle => "The Sims Bustin' Out (RLE textures)",
+            Game::TheUrbz => "The Urbz",
+            Game::TheSims2 => "The Sims 2",
+            Game::TheSims2Pets => "The Sims 2 Pets",
+            Game::TheSims2Castaway => "The Sims 2 Castaway",
+            Game::TheSims3 => "The Sims 3",
+        })
+    }
+}
+
+impl std::fmt::Display for Platform {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Platform::PlayStation2 => "PlayStation 2",
+            Platform::GameCube => "GameCube",
+            Platform::Xbox => "Xbox",
+            Platform::Wii => "Wii",
+        })
+    }
+}
+
+pub struct Texture {
+    pub name: String,
+    pub id: u32,
+    pub image: image::RgbaImage,
+    pub specular: bool,
+    pub info: texture::TextureInfo,
+}
+
+pub fn map_archive(archive_path: &std::path::Path) -> Result<memmap2::Mmap> {
+    let file = std::fs::File::open(archive_path).map_err(|error| Error::io(archive_path, error))?;
+    // Archives are only read, and changing one on disk while it is mapped is not supported.
+    unsafe { memmap2::Mmap::map(&file) }.map_err(|error| Error::io(archive_path, error))
+}
+
+enum ArchiveData {
+    Mapped(std::ops::Range<usize>, memmap2::Mmap),
+    Owned(Vec<u8>),
+}
+
+pub struct Archive {
+    data: ArchiveData,
+    pub disc_path: Option<String>,
+}
+
+impl std::ops::Deref for Archive {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        match &self.data {
+            ArchiveData::Mapped(range, map) => &map[range.clone()],
+            ArchiveData::Owned(bytes) => bytes,
+        }
+    }
+}
+
+fn read_disc_file(
+    bytes: &[u8],
+    disc_path: Option<&str>,
+    common_key: Option<&[u8; 16]>,
+    game: Option<Game>,
+) -> Result<(disc::File, Option<Vec<u8>>)> {
+    let image = disc::open(bytes, common_key)?;
+    let files = disc::files(image.as_ref())?;
+    let file = match disc_path {
+        Some(disc_path) => disc::find(files, disc_path)?,
+        None => disc::find_archive(files, game)?,
+    };
+
+    let bytes = match image.read(file.offset, file.length)? {
+        std::borrow::Cow::Borrowed(_) => None,
+        std::borrow::Cow::Owned(bytes) => Some(bytes),
+    };
+    Ok((file, bytes))
+}
+
+pub fn load_archive(
+    archive_path: &std::path::Path,
+    disc_path: Option<&str>,
+    common_key: Option<&[u8; 16]>,
+    game: Option<Game>,
+) -> Result<Archive> {
+    let map = map_archive(archive_path)?;
+    if !disc::is_disc(&map).map_err(|error| error.in_archive(archive_path))? {
+        return match disc_path {
+            Some(_) => Err(Error::NotADiscImage.in_archive(archive_path)),
+            None => Ok(Archive {
+                data: ArchiveData::Mapped(0..map.len(), map),
+                disc_path: None,
+            }),
+        };
+    }
+
+    let (file, bytes) =
+        read_disc_file(&map, disc_path, common_key, game).map_err(|error| error.in_archive(archive_path))?;
+    let data = match bytes {
+        Some(bytes) => ArchiveData::Owned(bytes),
+        None => ArchiveData::Mapped(file.offset..file.offset + file.length, map),
+    };
+    Ok(Archive {
+        data,
+        disc_path: Some(file.path),
+    })
+}
+
+pub fn open_archive(archive_path: &std::path::Path, game: Game, platform: Platform) -> Result<Vec<Texture>> {
+    let archive = load_archive(archive_path, None, None, Some(game))?;
+    decode_archive(&archive, game, platform).map_err(|error| error.in_archive(archive_path))
+}
+
+pub fn open_texture(archive_path: &std::path::Path, game: Game, platform: Platform, name: &str) -> Result<Texture> {
+    let archive = load_archive(archive_path, None, None, Some(game))?;
+    find_texture(&archive, game, platform, name).map_err(|error| error.in_archive(archive_path))
+}
+
+pub fn decode_archive(archive: &[u8], game: Game, platform: Platform) -> Result<Vec<Texture>> {
+    entries(archive, game, platform)?.map(|entry| decode_entry(&entry?, game, platform)).collect()
+}
+
+pub fn find_entry<'a>(archive: &'a [u8], game: Game, platform: Platform, name: &str) -> Result<arc::Entry<'a>> {
+    for entry in entries(archive, game, platform)? {
+        let entry = entry?;
+        if entry.name == name {
+            return Ok(entry);
+        }
+    }
+
+    Err(Error::EntryNotFound { name: name.to_owned() })
+}
+
+pub fn find_texture(archive: &[u8], game: Game, platform: Platform, name: &str) -> Result<Texture> {
+    let entry = find_entry(archive, game, platform, name)?;
+    decode_entry(&entry, game, platform)
+}
+
+fn endianness(game: Game, platform: Platform) -> Result<Endianness> {
+    match (game, platform) {
+        (
+            Game::TheSims | Game::TheSimsRle | Game::TheSimsBustinOut | Game::TheSimsBustinOutRle | Game::TheUrbz,
+            Platform::PlayStation2 | Platform::Xbox,
+        ) => Ok(Endianness::Little),
+        (
+            Game::TheSims | Game::TheSimsRle | Game::TheSimsBustinOut | Game::TheSimsBustinOutRle | Game::TheUrbz,
+            Platform::GameCube,
+        ) => Ok(Endianness::Big),
+        (Game::TheSims2, Platform::PlayStation2 | Platform::Xbox) => Ok(Endianness::Little),
+        (Game::TheSims2, Platform::GameCube) => Ok(Endianness::Big),
+        (Game::TheSims2Pets, Platform::PlayStation2) => Ok(Endianness::Little),
+        (Game::TheSims2Pets, Platform::GameCube | Platform::Wii) => Ok(Endianness::Big),
+        (Game::TheSims2Castaway, Platform::PlayStation2) => Ok(Endianness::Little),
+        (Game::TheSims2Castaway, Platform::Wii) => Ok(Endianness::Big),
+        (Game::TheSims3, Platform::Wii) => Ok(Endianness::Big),
+        _ => Err(Error::UnsupportedPlatform { game, platform }),
+    }
+}
+
+pub type Entries<'a> = Box<dyn Iterator<Item = Result<arc::Entry<'a>>> + 'a>;
+
+pub fn entries(archive: &[u8], game: Game, platform: Platform) -> Result<Entries<'_>> {
+    let endianness = endianness(game, platform)?;
+    match game {
+        Game::TheSims => Ok(Box::new(datasets::textures(archive, endianness))),
+        _ => Ok(Box::new(arc::entries(archive, endianness)?)),
+    }
+}
+
+pub fn list_entries(archive: &[u8], game: Game, platform: Platform) -> Result<Vec<arc::Entry<'_>>> {
+    entries(archive, game, platform)?.collect()
+}
+
+pub fn texture_info(bytes: &[u8], game: Game, platform: Platform) -> Result<texture::TextureInfo> {
+    match (game, platform) {
+        (Game::TheSims, Platform::PlayStation2) => the_sims::playstation_2_texture_info(bytes),
+        (Game::TheSims, Platform::GameCube) => the_sims::gamecube_texture_info(bytes),
+        (Game::TheSims, Platform::Xbox) => the_sims::xbox_texture_info(bytes),
+        (Game::TheSimsRle, _) => rle_textures::texture_info(bytes, false),
+        (Game::TheSimsBustinOut, Platform::PlayStation2) => the_sims_bustin_out::playstation_2_texture_info(bytes),
+        (Game::TheSimsBustinOut, Platform::GameCube) => the_sims_bustin_out::gamecube_texture_info(bytes),
+        (Game::TheSimsBustinOut, Platform::Xbox) => the_sims_bustin_out::xbox_texture_info(bytes),
+        (Game::TheSimsBustinOutRle, _) => rle_textures::texture_info(bytes, true),
+        (Game::TheUrbz, Platform::PlayStation2) => the_urbz::playstation_2_texture_info(bytes),
+        (Game::TheUrbz, Platform::GameCube) => the_urbz::gamecube_texture_info(bytes),
+        (Game::TheUrbz, Platform::Xbox) => the_urbz::xbox_texture_info(bytes),
+        (Game::TheSims2 | Game::TheSims2Pets | Game::TheSims2Castaway, Platform::PlayStation2) => {
+            the_sims_2::playstation_2_texture_info(bytes)
+        }
+        (Game::TheSims2 | Game::TheSims2Pets | Game::TheSims2Castaway, Platform::GameCube | Platform::Wii) => {
+            the_sims_2::gamecube_texture_info(bytes)
+        }
+        (Game::TheSims2, Platform::Xbox) => the_sims_2::xbox_texture_info(bytes),
+        (Game::TheSims3, Platform::Wii) => the_sims_3::texture_info(bytes),
+        _ => Err(Error::UnsupportedPlatform { game, platform }),
+    }
+}
+
+pub fn is_specular(entry: &arc::Entry, game: Game) -> bool {
+    let name = entry.name.as_str();
+    match game {
+        Game::TheSims => !the_sims::THE_SIMS_ALPHA_TEXTURE_IDS.contains(&entry.id),
+        Game::TheSimsRle | Game::TheSimsBustinOutRle => false,
+        Game::TheSimsBustinOut => !the_sims_bustin_out::ALPHA_TEXTURE_IDS.contains(&entry.id),
+        Game::TheUrbz => the_urbz::SPECULAR_FILE_NAMES.contains(&name),
+        Game::TheSims2 => the_sims_2::SPECULAR_FILE_NAMES.contains(&name),
+        Game::TheSims2Pets => the_sims_2_pets::SPECULAR_FILE_NAMES.contains(&name),
+        Game::TheSims2Castaway => the_sims_2_castaway::SPECULAR_FILE_NAMES.contains(&name),
+        Game::TheSims3 => the_sims_3::SPECULAR_FILE_NAMES.contains(&name),
+    }
+}
+
+pub fn decode_entry(entry: &arc::Entry, game: Game, platform: Platform) -> Result<Texture> {
+    let info = texture_info(entry.bytes, game, platform).map_err(|error| error.in_entry(entry))?;
+    let image = texture::decode(entry.bytes, &info).map_err(|error| error.in_entry(entry))?;
+
+    Ok(Texture {
+        name: entry.name.clone(),
+        id: entry.id,
+        image,
+        specular: is_specular(entry, game),
+        info,
+    })
+}
+
+pub fn decode_mipmaps(entry: &arc::Entry, game: Game, platform: Platform) -> Result<Vec<Texture>> {
+    let info = texture_info(entry.bytes, game, platform).map_err(|error| error.in_entry(entry))?;
+    let levels = texture::check_mip_chain(entry.bytes, &info).map_err(|error| error.in_entry(entry))?;
+    decode_levels(entry, game, &levels)
+}
+
+fn decode_levels(entry: &arc::Entry, game: Game, levels: &[texture::TextureInfo]) -> Result<Vec<Texture>> {
+    let mut mipmaps = Vec::new();
+    for (level, level_info) in levels.iter().enumerate().skip(1) {
+        let image = texture::decode(entry.bytes, level_info).map_err(|error| error.in_entry(entry))?;
+        mipmaps.push(Texture {
+            name: format!("{}.mip{level}", entry.name),
+            id: entry.id,
+            image,
+            specular: is_specular(entry, game),
+            info: level_info.clone(),
+        });
+    }
+
+    Ok(mipmaps)
+}
+
+pub fn replace_texture(
+    archive: &[u8],
+    game: Game,
+    platform: Platform,
+    entry_name: &str,
+    image: &image::RgbaImage,
+) -> Result<Vec<u8>> {
+    let mut entries = list_entries(archive, game, platform)?;
+    let index = entries.iter().position(|entry| entry.name == entry_name).ok_or(Error::EntryNotFound {
+        name: entry_name.to_owned(),
+    })?;
+
+    let entry = &entries[index];
+    let entry_bytes = texture_info(entry.bytes, game, platform)
+        .and_then(|info| texture::encode(entry.bytes, &info, image))
+        .map_err(|error| error.in_entry(entry))?;
+
+    if entry_bytes.len() == entry.bytes.len() {
+        let mut archive = archive.to_vec();
+        archive[entry.offset..entry.offset + entry_bytes.len()].copy_from_slice(&entry_bytes);
+        return Ok(archive);
+    }
+
+    // The TXFL chunks of datasets.arc sit inside other data, so they can only be replaced in place.
+    if game == Game::TheSims {
+        return Err(Error::EntrySizeChanged {
+            length: entry.bytes.len(),
+            encoded_length: entry_bytes.len(),
+        }
+        .in_entry(entry));
+    }
+
+    entries[index].bytes = &entry_bytes;
+    arc::update_files(archive, &entries, endianness(game, platform)?)
+}
+
+#[derive(Default)]
+pub struct ExtractOptions {
+    pub keep_going: bool,
+    pub mipmaps: bool,
+    pub metadata: Option<metadata::Metadata>,
+    pub source_archive: Option<std::path::PathBuf>,
+    pub texture_flags: texture_flags::TextureFlags,
+    pub classify_alpha: bool,
+    pub format: OutputFormat,
+    pub dds: bool,
+    pub indexed: bool,
+    pub palettes: bool,
+    pub jobs: Option<usize>,
+    pub filter: filter::Filter,
+}
+
+pub struct Failure {
+    pub name: String,
+    pub id: u32,
+    pub offset: usize,
+    pub error: Error,
+}
+
+#[derive(Default)]
+pub struct ExtractReport {
+    pub extracted: usize,
+    pub failures: Vec<Failure>,
+    pub warnings: Vec<Failure>,
+}
+
+impl ExtractReport {
+    pub fn write(&self, path: &std::path::Path) -> Result<()> {
+        let mut report = format!("extracted: {}\nfailed: {}\n", self.extracted, self.failures.len());
+        for failure in &self.failures {
+            report += &format!(
+                "{} (id {}, offset {:#X}): {}\n",
+                failure.name, failure.id, failure.offset, failure.error
+            );
+        }
+
+        std::fs::write(path, report).map_err(|error| Error::io(path, error))
+    }
+}
+
+pub fn extract_archive(
+    archive_path: &std::path::Path,
+    output_path: &std::path::Path,
+    game: Game,
+    platform: Platform,
+    options: &ExtractOptions,
+) -> Result<ExtractReport> {
+    let archive = load_archive(archive_path, None, None, Some(game))?;
+    extract_textures(&archive, output_path, game, platform, options).map_err(|error| error.in_archive(archive_path))
+}
+
+type ExtractedEntry<'a> = (metadata::TextureMetadata, Option<(&'a [u8], usize)>, Vec<Error>);
+
+fn extract_entry<'a>(
+    entry: &arc::Entry<'a>,
+    output_path: &std::path::Path,
+    game: Game,
+    platform: Platform,
+    options: &ExtractOptions,
+) -> Result<ExtractedEntry<'a>> {
+    let mut texture = decode_entry(entry, game, platform)?;
+
+    // A texture whose data does not match a mip chain is written with only its top level.
+    let mut warnings = Vec::new();
+    let dds = options.dds && dds::is_supported(texture.info.format) && texture.info.rle_bit_count.is_none();
+    let levels = if options.mipmaps || options.metadata.is_some() || dds {
+        texture::check_mip_chain(entry.bytes, &texture.info).unwrap_or_else(|error| {
+            warnings.push(error);
+            vec![texture.info.clone()]
+        })
+    } else {
+        vec![texture.info.clone()]
+    };
+    let mut mipmaps = if options.mipmaps && !dds {
+        decode_levels(entry, game, &levels)?
+    } else {
+        Vec::new()
+    };
+
+    let specular = match options.texture_flags.specular(entry) {
+        Some(specular) => Some(specular),
+        None if options.classify_alpha => Some(classify_alpha(&texture.image) == AlphaUsage::Specular),
+        None => None,
+    };
+    if let Some(specular) = specular {
+        for texture in std::iter::once(&mut texture).chain(&mut mipmaps) {
+            texture.specular = specular;
+        }
+    }
+
+    if dds {
+        let dds_path = output_path.join(format!("{}.dds", texture.name));
+        let dds = dds::write(entry.bytes, &texture.info, &levels[1..]).map_err(|error| error.in_entry(entry))?;
+        std::fs::write(&dds_path, dds).map_err(|error| Error::io(&dds_path, error))?;
+    } else {
+        for texture in std::iter::once(&texture).chain(&mipmaps) {
+            let indexed = if options.indexed {
+                texture::decode_indexed(entry.bytes, &texture.info).map_err(|error| error.in_entry(entry))?
+            } else {
+                None
+            };
+            match indexed {
+                Some((indices, palette)) => save_indexed_texture(texture, &indices, &palette, output_path),
+                None => save_texture(texture, output_path, options.format),
+            }
+            .map_err(|error| error.in_entry(entry))?;
+        }
+    }
+
+    let mut palette = None;
+    if options.palettes {
+        let indexed = texture::decode_indexed(entry.bytes, &texture.info).map_err(|error| error.in_entry(entry))?;
+        if let (Some((_, colors)), Some(palette_offset)) = (indexed, texture.info.palette_offset) {
+            let palette_bytes =
+                bytes::slice(entry.bytes, palette_offset, colors.len() * 4).map_err(|error| error.in_entry(entry))?;
+            palette::write(&texture.name, &colors, output_path)?;
+            palette = Some((palette_bytes, colors.len()));
+        }
+    }
+
+    let texture_metadata = metadata::TextureMetadata::new(
+        &texture,
+        levels.len(),
+        game,
+        platform,
+        options.source_archive.as_deref(),
+    );
+    Ok((texture_metadata, palette, warnings))
+}
+
+pub fn extract_textures(
+    archive: &[u8],
+    output_path: &std::path::Path,
+    game: Game,
+    platform: Platform,
+    options: &ExtractOptions,
+) -> Result<ExtractReport> {
+    std::fs::create_dir_all(output_path).map_err(|error| Error::io(output_path, error))?;
+
+    let mut entries = list_entries(archive, game, platform)?;
+    entries.retain(|entry| options.filter.matches(entry, game, platform));
+
+    // Entries whose names only differ in case write the same files on case-insensitive file systems, so they stay
+    // on one thread in archive order.
+    let mut name_groups: Vec<Vec<(usize, arc::Entry)>> = Vec::new();
+    let mut group_indices = std::collections::HashMap::new();
+    for (index, entry) in entries.into_iter().enumerate() {
+        let group_index = *group_indices.entry(entry.name.to_lowercase()).or_insert_with(|| {
+            name_groups.push(Vec::new());
+            name_groups.len() - 1
+        });
+        name_groups[group_index].push((index, entry));
+    }
+
+    use rayon::iter::{IntoParallelIterator, ParallelIterator};
+    let thread_pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(options.jobs.unwrap_or(0))
+        .build()
+        .map_err(|source| Error::ThreadPool { source })?;
+    let aborted = std::sync::atomic::AtomicBool::new(false);
+    let mut results = thread_pool.install(|| {
+        name_groups
+            .into_par_iter()
+            .flat_map_iter(|group| {
+                group.into_iter().map_while(|(index, entry)| {
+                    if aborted.load(std::sync::atomic::Ordering::Relaxed) {
+                        return None;
+                    }
+
+                    let result = extract_entry(&entry, output_path, game, platform, options);
+                    let fatal = match &result {
+                        Ok(_) => false,
+                        Err(Error::Entry { .. }) => !options.keep_going,
+                        Err(_) => true,
+                    };
+                    if fatal {
+                        aborted.store(true, std::sync::atomic::Ordering::Relaxed);
+                    }
+                    Some((index, entry, result))
+                })
+            })
+            .collect::<Vec<_>>()
+    });
+    results.sort_by_key(|(index, _, _)| *index);
+
+    let mut report = ExtractReport::default();
+    let mut manifest = Vec::new();
+    let mut palette_groups = palette::PaletteGroups::default();
+    for (_, entry, result) in results {
+        match result {
+            Ok((texture_metadata, palette, warnings)) => {
+                report.extracted += 1;
+                report.warnings.extend(warnings.into_iter().map(|error| Failure {
+                    name: entry.name.clone(),
+                    id: entry.id,
+                    offset: entry.offset,
+                    error,
+                }));
+
+                if let Some((palette, color_count)) = palette {
+                    palette_groups.add(palette, color_count, &texture_metadata.name);
+                }
+
+                match options.metadata {
+                    Some(metadata::Metadata::Sidecar) => {
+                        let path = output_path.join(format!("{}.json", texture_metadata.name));
+                        metadata::write(&texture_metadata, &path)?;
+                    }
+                    Some(metadata::Metadata::Manifest) => manifest.push(texture_metadata),
+                    None => {}
+                }
+            }
+            Err(Error::Entry { source, .. }) if options.keep_going => {
+                save_raw_entry(&entry, output_path)?;
+
+                report.failures.push(Failure {
+                    name: entry.name,
+                    id: entry.id,
+                    offset: entry.offset,
+                    error: *source,
+                });
+            }
+            Err(error) => return Err(error),
+        }
+    }
+
+    if options.metadata == Some(metadata::Metadata::Manifest) {
+        metadata::write(&manifest, &output_path.join("manifest.json"))?;
+    }
+
+    if options.palettes {
+        palette_groups.write(&output_path.join("palettes.txt"))?;
+    }
+
+    Ok(report)
+}
+
+pub fn extract_raw_entries(
+    archive: &[u8],
+    output_path: &std::path::Path,
+    endianness: Endianness,
+    filter: &filter::Filter,
+    game_and_platform: Option<(Game, Platform)>,
+) -> Result<usize> {
+    std::fs::create_dir_all(output_path).map_err(|error| Error::io(output_path, error))?;
+
+    let mut entries = arc::list_files(archive, endianness)?;
+    entries.retain(|entry| filter.matches_raw(entry, game_and_platform));
+    for entry in &entries {
+        save_raw_entry(entry, output_path)?;
+    }
+
+    Ok(entries.len())
+}
+
+pub fn save_raw_entry(entry: &arc::Entry, output_path: &std::path::Path) -> Result<()> {
+    let raw_path = output_path.join(format!("{}.{}.bin", entry.name, entry.id));
+    std::fs::write(&raw_path, entry.bytes).map_err(|error| Error::io(&raw_path, error))
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    #[default]
+    Png,
+    Tga,
+    WebP,
+    Qoi,
+}
+
+impl OutputFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            OutputFormat::Png => "png",
+            OutputFormat::Tga => "tga",
+            OutputFormat::WebP => "webp",
+            OutputFormat::Qoi => "qoi",
+        }
+    }
+
+    fn image_format(&self) -> image::ImageFormat {
+        match self {
+            OutputFormat::Png => image::ImageFormat::Png,
+            OutputFormat::Tga => image::ImageFormat::Tga,
+            OutputFormat::WebP => image::ImageFormat::WebP,
+            OutputFormat::Qoi => image::ImageFormat::Qoi,
+        }
+    }
+}
+
+fn save_image<P, C>(image: &image::ImageBuffer<P, C>, path: std::path::PathBuf, format: OutputFormat) -> Result<()>
+where
+    P: image::PixelWithColorType,
+    [P::Subpixel]: image::EncodableLayout,
+    C: std::ops::Deref<Target = [P::Subpixel]>,
+{
+    image.save_with_format(&path, format.image_format()).map_err(|source| Error::Image { path, source })
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AlphaUsage {
+    Unused,
+    Transparency,
+    Specular,
+}
+
+impl std::fmt::Display for AlphaUsage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AlphaUsage::Unused => write!(f, "unused"),
+            AlphaUsage::Transparency => write!(f, "transparency"),
+            AlphaUsage::Specular => write!(f, "specular"),
+        }
+    }
+}
+
+pub fn classify_alpha(image: &image::RgbaImage) -> AlphaUsage {
+    let pixel_count = f64::from(image.width() * image.height());
+    if pixel_count == 0.0 {
+        return AlphaUsage::Unused;
+    }
+
+    let first_alpha = image.pixels().next().map_or(255, |pixel| pixel.0[3]);
+    if image.pixels().all(|pixel| pixel.0[3] == first_alpha) {
+        return AlphaUsage::Unused;
+    }
+
+    let (width, height) = image.dimensions();
+    let edge_pixels = image
+        .enumerate_pixels()
+        .filter(|(x, y, _)| *x == 0 || *y == 0 || *x == width - 1 || *y == height - 1)
+        .map(|(_, _, pixel)| pixel)
+        .collect::<Vec<_>>();
+    let transparent_edge_count = edge_pixels.iter().filter(|pixel| pixel.0[3] < 16).count();
+    if transparent_edge_count * 2 >= edge_pixels.len() {
+        return AlphaUsage::Transparency;
+    }
+
+    let binary_count = image.pixels().filter(|pixel| matches!(pixel.0[3], 0 | 255)).count();
+    if binary_count as f64 >= pixel_count * 0.95 {
+        return AlphaUsage::Transparency;
+    }
+
+    let mut alpha_sum = 0.0;
+    let mut luminance_sum = 0.0;
+    let mut alpha_squared_sum = 0.0;
+    let mut luminance_squared_sum = 0.0;
+    let mut product_sum = 0.0;
+    for pixel in image.pixels() {
+        let [r, g, b, a] = pixel.0.map(f64::from);
+        let luminance = (r * 0.299) + (g * 0.587) + (b * 0.114);
+        alpha_sum += a;
+        luminance_sum += luminance;
+        alpha_squared_sum += a * a;
+        luminance_squared_sum += luminance * luminance;
+        product_sum += a * luminance;
+    }
+
+    let covariance = (product_sum / pixel_count) - ((alpha_sum / pixel_count) * (luminance_sum / pixel_count));
+    let alpha_variance = (alpha_squared_sum / pixel_count) - (alpha_sum / pixel_count).powi(2);
+    let luminance_variance = (luminance_squared_sum / pixel_count) - (luminance_sum / pixel_count).powi(2);
+    let correlation = if alpha_variance > 0.0 && luminance_variance > 0.0 {
+        covariance / (alpha_variance * luminance_variance).sqrt()
+    } else {
+        0.0
+    };
+
+    if correlation.abs() >= 0.5 || alpha_sum / pixel_count >= 128.0 {
+        AlphaUsage::Specular
+    } else {
+        AlphaUsage::Transparency
+    }
+}
+
+fn split_alpha(image: &image::RgbaImage) -> (image::RgbImage, image::GrayImage) {
+    let pixel_count = image.width() as usize * image.height() as usize;
+    let mut diffuse = Vec::with_capacity(pixel_count * 3);
+    let mut alpha = Vec::with_capacity(pixel_count);
+    for pixel in image.as_raw().chunks_exact(4) {
+        diffuse.extend_from_slice(&pixel[..3]);
+        alpha.push(pixel[3]);
+    }
+
+    (
+        image::RgbImage::from_raw(image.width(), image.height(), diffuse).unwrap(),
+        image::GrayImage::from_raw(image.width(), image.height(), alpha).unwrap(),
+    )
+}
+
+fn save_specular(specular: &image::GrayImage, path: std::path::PathBuf, format: OutputFormat) -> Result<()> {
+    if specular.iter().all(|alpha| *alpha == 0) || specular.iter().all(|alpha| *alpha == 255) {
+        return Ok(());
+    }
+
+    if format == OutputFormat::Qoi {
+        save_image(
+            &image::DynamicImage::ImageLuma8(specular.clone()).to_rgb8(),
+            path,
+            format,
+        )
+    } else {
+        save_image(specular, path, format)
+    }
+}
+
+pub fn save_texture(texture: &Texture, output_path: &std::path::Path, format: OutputFormat) -> Result<()> {
+    let extension = format.extension();
+    let image = &texture.image;
+    let name = &texture.name;
+    let path = output_path.join(format!("{name}.{extension}"));
+    if texture.specular {
+        let (diffuse, specular) = split_alpha(image);
+        save_image(&diffuse, path, format)?;
+        save_specular(
+            &specular,
+            output_path.join(format!("{name} specular.{extension}")),
+            format,
+        )
+    } else if image.pixels().any(|pixel| pixel.0[3] != 255) {
+        save_image(image, path, format)
+    } else {
+        let (diffuse, _) = split_alpha(image);
+        save_image(&diffuse, path, format)
+    }
+}
+
+pub fn save_indexed_texture(
+    texture: &Texture,
+    indices: &image::GrayImage,
+    palette: &[[u8; 4]],
+    output_path: &std::path::Path,
+) -> Result<()> {
+    let name = &texture.name;
+    let path = output_path.join(format!("{name}.png"));
+    let (width, height) = indices.dimensions();
+
+    let bit_depth = if palette.len() <= 16 {
+        png::BitDepth::Four
+    } else {
+        png::BitDepth::Eight
+    };
+    let data = match bit_depth {
+        png::BitDepth::Four => indices
+            .rows()
+            .flat_map(|row| {
+                let row = row.map(|index| index.0[0]).collect::<Vec<_>>();
+                row.chunks(2).map(|pair| (pair[0] << 4) | pair.get(1).copied().unwrap_or(0)).collect::<Vec<_>>()
+            })
+            .collect(),
+        _ => indices.as_raw().clone(),
+    };
+
+    let mut alphas = if texture.specular {
+        Vec::new()
+    } else {
+        palette.iter().map(|color| color[3]).collect()
+    };
+    while alphas.last() == Some(&255) {
+        alphas.pop();
+    }
+
+    let file = std::fs::File::create(&path).map_err(|error| Error::io(&path, error))?;
+    let mut encoder = png::Encoder::new(std::io::BufWriter::new(file), width, height);
+    encoder.set_color(png::ColorType::Indexed);
+    encoder.set_depth(bit_depth);
+    encoder.set_palette(palette.iter().flat_map(|color| [color[0], color[1], color[2]]).collect::<Vec<_>>());
+    if !alphas.is_empty() {
+        encoder.set_trns(alphas);
+    }
+    encoder
+        .write_header()
+        .and_then(|mut writer| writer.write_image_data(&data))
+        .map_err(|source| Error::Png { path, source })?;
+
+    if texture.specular {
+        let (_, specular) = split_alpha(&texture.image);
+        save_specular(
+            &specular,
+            output_path.join(format!("{name} specular.png")),
+            OutputFormat::Png,
+        )?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Game, Platform};
+
+    fn datasets() -> Vec<u8> {
+        let mut texture = b"TXFL".to_vec();
+        texture.extend_from_slice(b"floor");
+        let mut header = [0; 21];
+        header[3..5].copy_from_slice(&4u16.to_le_bytes());
+        header[5..7].copy_from_slice(&4u16.to_le_bytes());
+        texture.extend_from_slice(&header);
+        texture.resize(texture.len() + 4 * 4 * 4, 0x11);
+
+        let mut datasets = b"other data".to_vec();
+        datasets.extend_from_slice(&7u32.to_le_bytes());
+        datasets.extend_from_slice(&(texture.len() as u32).to_le_bytes());
+        datasets.extend_from_slice(&[0; 4]);
+        datasets.extend_from_slice(&texture);
+        datasets.extend_from_slice(b"more data");
+        datasets
+    }
+
+    #[test]
+    fn replace_in_datasets() {
+        let datasets = datasets();
+        let image = image::RgbaImage::from_pixel(4, 4, image::Rgba([10, 20, 30, 255]));
+        let replaced =
+            crate::replace_texture(&datasets, Game::TheSims, Platform::PlayStation2, "floor", &image).unwrap();
+        assert_eq!(replaced.len(), datasets.len());
+        assert!(replaced.starts_with(b"other data") && replaced.ends_with(b"more data"));
+
+        let texture = crate::find_texture(&replaced, Game::TheSims, Platform::PlayStation2, "floor").unwrap();
+        assert_eq!(texture.image, image);
+    }
+
+    fn texture_bytes(bit_count: u8, texture_type: u8, data_length: usize) -> Vec<u8> {
+        let mut header = vec![0; 37];
+        header[21..23].copy_from_slice(&8u16.to_le_bytes());
+        header[23..25].copy_from_slice(&8u16.to_le_bytes());
+        header[25..27].copy_from_slice(&16u16.to_le_bytes());
+        header[30] = texture_type;
+        header[31] = bit_count;
+        let mut bytes = [&[0; 16][..], b"wall", &header].concat();
+        bytes.resize(bytes.len() + data_length, 0);
+        bytes
+    }
+
+    fn archive(files: &[(&str, &[u8])]) -> Vec<u8> {
+        let entries = files
+            .iter()
+            .enumerate()
+            .map(|(id, (name, bytes))| crate::arc::Entry {
+                name: name.to_string(),
+                id: id as u32,
+                offset: 0,
+                bytes,
+                trailer: [0; 8],
+            })
+            .collect::<Vec<_>>();
+        crate::arc::write_files(&entries, crate::Endianness::Little).unwrap()
+    }
+
+    fn replace_mipmaps(archive: &[u8], image: &image::RgbaImage) -> Vec<crate::Texture> {
+        let replaced = crate::replace_texture(archive, Game::TheSims2, Platform::PlayStation2, "wall", image).unwrap();
+        assert_eq!(replaced.len(), archive.len());
+        let entries = crate::list_entries(&replaced, Game::TheSims2, Platform::PlayStation2).unwrap();
+        let texture = crate::decode_entry(&entries[0], Game::TheSims2, Platform::PlayStation2).unwrap();
+        let mipmaps = crate::decode_mipmaps(&entries[0], Game::TheSims2, Platform::PlayStation2).unwrap();
+        std::iter::once(texture).chain(mipmaps).collect()
+    }
+
+    #[test]
+    fn replace_every_mip_level() {
+        let image = image::RgbaImage::from_fn(8, 8, |x, y| image::Rgba([(x * 32) as u8, (y * 32) as u8, 90, 255]));
+        let archive = archive(&[("wall", &texture_bytes(32, 0, (8 * 8 + 4 * 4 + 2 * 2 + 1) * 4))]);
+        let levels = replace_mipmaps(&archive, &image);
+        assert_eq!(levels.len(), 4);
+        for level in levels {
+            let (width, height) = level.image.dimensions();
+            let expected = image::imageops::resize(&image, width, height, image::imageops::FilterType::Triangle);
+            assert_eq!(level.image, expected);
+        }
+    }
+
+    #[test]
+    fn replace_every_paletted_mip_level() {
+        let colors = [[255, 0, 0, 255], [0, 255, 0, 255], [0, 0, 255, 255], [255, 255, 255, 0]];
+        let image = image::RgbaImage::from_fn(8, 8, |x, y| image::Rgba(colors[(x / 2 % 2 + y / 4 * 2) as usize]));
+        let archive = archive(&[("wall", &texture_bytes(4, 2, 8 * 8 / 2 + 4 * 4 / 2 + 2 * 2 / 2 + 16 * 4))]);
+        let levels = replace_mipmaps(&archive, &image);
+        assert_eq!(levels.len(), 3);
+        for level in levels {
+            let (width, height) = level.image.dimensions();
+            let expected = image::imageops::resize(&image, width, height, image::imageops::FilterType::Nearest);
+            assert_eq!(level.image, expected);
+        }
+    }
+
+    #[test]
+    fn extract_stops_after_first_error() {
+        let output_path = std::env::temp_dir().join(format!("tsc-texture-extractor-extract-{}", std::process::id()));
+        let archive = archive(&[("bad", b"bad"), ("good", &texture_bytes(32, 0, 8 * 8 * 4))]);
+        let mut options = crate::ExtractOptions {
+            jobs: Some(1),
+            ..Default::default()
+        };
+
+        let result = crate::extract_textures(&archive, &output_path, Game::TheSims2, Platform::PlayStation2, &options);
+        assert!(matches!(result, Err(crate::Error::Entry { .. })));
+        assert!(!output_path.join("good.png").exists());
+
+        options.keep_going = true;
+        let report =
+            crate::extract_textures(&archive, &output_path, Game::TheSims2, Platform::PlayStation2, &options).unwrap();
+        assert_eq!((report.extracted, report.failures.len()), (1, 1));
+        assert!(output_path.join("good.png").exists());
+        std::fs::remove_dir_all(&output_path).unwrap();
+    }
+
+    fn classify(alpha: impl Fn(u32, u32, u8) -> u8) -> crate::AlphaUsage {
+        let image = image::RgbaImage::from_fn(16, 16, |x, y| {
+            let luminance = ((x + y) * 8) as u8;
+            image::Rgba([luminance, luminance, luminance, alpha(x, y, luminance)])
+        });
+        crate::classify_alpha(&image)
+    }
+
+    #[test]
+    fn classify_constant_alpha() {
+        assert_eq!(classify(|_, _, _| 255), crate::AlphaUsage::Unused);
+        assert_eq!(classify(|_, _, _| 40), crate::AlphaUsage::Unused);
+    }
+
+    #[test]
+    fn classify_transparent_edges() {
+        let inside = |x: u32, y: u32| (1..15).contains(&x) && (1..15).contains(&y);
+        assert_eq!(
+            classify(|x, y, luminance| if inside(x, y) { luminance } else { 0 }),
+            crate::AlphaUsage::Transparency
+        );
+    }
+
+    #[test]
+    fn classify_binary_alpha() {
+        assert_eq!(
+            classify(|x, y, _| if (x / 4 + y / 4) % 2 == 0 { 255 } else { 0 }),
+            crate::AlphaUsage::Transparency
+        );
+    }
+
+    #[test]
+    fn classify_graded_alpha() {
+        assert_eq!(
+            classify(|_, _, luminance| luminance / 2 + 64),
+            crate::AlphaUsage::Specular
+        );
+        // x - y does not correlate with the luminance, so only the mean alpha decides.
+        assert_eq!(
+            classify(|x, y, _| (160 + x * 2) as u8 - (y * 2) as u8),
+            crate::AlphaUsage::Specular
+        );
+        assert_eq!(
+            classify(|x, y, _| (40 + x * 2) as u8 - (y * 2) as u8),
+            crate::AlphaUsage::Transparency
+        );
+    }
+
+    #[test]
+    fn classify_empty_image() {
+        assert_eq!(
+            crate::classify_alpha(&image::RgbaImage::new(0, 0)),
+            crate::AlphaUsage::Unused
+        );
+    }
+}
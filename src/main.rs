@@ -1,82 +1,4 @@
-mod arc;
-mod datasets;
-mod gamecube;
-mod playstation_2;
-mod rle_textures;
-mod the_sims;
-mod the_sims_2;
-mod the_sims_2_castaway;
-mod the_sims_2_pets;
-mod the_sims_3;
-mod the_sims_bustin_out;
-mod the_urbz;
-mod xbox;
-
-pub enum Endianness {
-    Little,
-    Big,
-}
-
-impl Endianness {
-    pub fn u32_from_bytes(&self, bytes: [u8; 4]) -> u32 {
-        match self {
-            Endianness::Little => u32::from_le_bytes(bytes),
-            Endianness::Big => u32::from_be_bytes(bytes),
-        }
-    }
-}
-
-fn save_texture(image: image::RgbaImage, name: &str, output_path: &std::path::Path, specular: bool) {
-    if specular {
-        let mut diffuse = image::RgbImage::new(image.width(), image.height());
-        let mut specular = image::ImageBuffer::<image::Luma<u8>, Vec<u8>>::new(image.width(), image.height());
-        let mut alpha_0_count = 0;
-        let mut alpha_255_count = 0;
-        for y in 0..image.height() {
-            for x in 0..image.width() {
-                let pixel = image.get_pixel(x, y);
-                diffuse.put_pixel(x, y, image::Rgb(pixel.0[0..3].try_into().unwrap()));
-                specular.put_pixel(x, y, image::Luma([pixel.0[3]]));
-
-                if pixel.0[3] == 0 {
-                    alpha_0_count += 1
-                }
-
-                if pixel.0[3] == 255 {
-                    alpha_255_count += 1
-                }
-            }
-        }
-
-        let pixel_count = image.width() * image.height();
-
-        diffuse.save(output_path.join(format!("{}.png", name))).unwrap();
-        if !(alpha_0_count == pixel_count || alpha_255_count == pixel_count) {
-            specular.save(output_path.join(format!("{} specular.png", name))).unwrap();
-        }
-    } else {
-        let mut has_alpha = false;
-        for pixel in image.pixels() {
-            if pixel.0[3] != 255 {
-                has_alpha = true;
-            }
-        }
-
-        if has_alpha {
-            image.save(output_path.join(format!("{}.png", name))).unwrap();
-        } else {
-            let mut diffuse = image::RgbImage::new(image.width(), image.height());
-            for y in 0..image.height() {
-                for x in 0..image.width() {
-                    let pixel = image.get_pixel(x, y);
-                    diffuse.put_pixel(x, y, image::Rgb(pixel.0[0..3].try_into().unwrap()));
-                }
-            }
-
-            diffuse.save(output_path.join(format!("{}.png", name))).unwrap();
-        }
-    }
-}
+use tsc_texture_extractor::{Error, Game, Platform};
 
 #[derive(clap::Parser)]
 #[command(version, about, long_about = None)]
@@ -115,162 +37,988 @@ enum Console3 {
     Wii,
 }
 
+impl From<&Console0> for Platform {
+    fn from(console: &Console0) -> Self {
+        match console {
+            Console0::PlayStation2 => Platform::PlayStation2,
+            Console0::GameCube => Platform::GameCube,
+            Console0::Xbox => Platform::Xbox,
+        }
+    }
+}
+
+impl From<&Console1> for Platform {
+    fn from(console: &Console1) -> Self {
+        match console {
+            Console1::PlayStation2 => Platform::PlayStation2,
+            Console1::GameCube => Platform::GameCube,
+            Console1::Wii => Platform::Wii,
+        }
+    }
+}
+
+impl From<&Console2> for Platform {
+    fn from(console: &Console2) -> Self {
+        match console {
+            Console2::PlayStation2 => Platform::PlayStation2,
+            Console2::Wii => Platform::Wii,
+        }
+    }
+}
+
+impl From<&Console3> for Platform {
+    fn from(console: &Console3) -> Self {
+        match console {
+            Console3::Wii => Platform::Wii,
+        }
+    }
+}
+
+#[derive(Clone, clap::ValueEnum)]
+enum GameArg {
+    TheSims,
+    TheSimsRle,
+    TheSimsBustinOut,
+    TheSimsBustinOutRle,
+    TheUrbz,
+    #[clap(name = "the-sims-2")]
+    TheSims2,
+    #[clap(name = "the-sims-2-pets")]
+    TheSims2Pets,
+    #[clap(name = "the-sims-2-castaway")]
+    TheSims2Castaway,
+    #[clap(name = "the-sims-3")]
+    TheSims3,
+}
+
+impl From<&GameArg> for Game {
+    fn from(game: &GameArg) -> Self {
+        match game {
+            GameArg::TheSims => Game::TheSims,
+            GameArg::TheSimsRle => Game::TheSimsRle,
+            GameArg::TheSimsBustinOut => Game::TheSimsBustinOut,
+            GameArg::TheSimsBustinOutRle => Game::TheSimsBustinOutRle,
+            GameArg::TheUrbz => Game::TheUrbz,
+            GameArg::TheSims2 => Game::TheSims2,
+            GameArg::TheSims2Pets => Game::TheSims2Pets,
+            GameArg::TheSims2Castaway => Game::TheSims2Castaway,
+            GameArg::TheSims3 => Game::TheSims3,
+        }
+    }
+}
+
+#[derive(Clone, clap::ValueEnum)]
+enum PlatformArg {
+    #[clap(name = "ps2")]
+    PlayStation2,
+    #[clap(name = "gamecube")]
+    GameCube,
+    Xbox,
+    Wii,
+}
+
+impl From<&PlatformArg> for Platform {
+    fn from(platform: &PlatformArg) -> Self {
+        match platform {
+            PlatformArg::PlayStation2 => Platform::PlayStation2,
+            PlatformArg::GameCube => Platform::GameCube,
+            PlatformArg::Xbox => Platform::Xbox,
+            PlatformArg::Wii => Platform::Wii,
+        }
+    }
+}
+
+#[derive(Clone, clap::ValueEnum)]
+enum EndiannessArg {
+    Little,
+    Big,
+}
+
+impl From<&EndiannessArg> for tsc_texture_extractor::Endianness {
+    fn from(endianness: &EndiannessArg) -> Self {
+        match endianness {
+            EndiannessArg::Little => tsc_texture_extractor::Endianness::Little,
+            EndiannessArg::Big => tsc_texture_extractor::Endianness::Big,
+        }
+    }
+}
+
+#[derive(Clone, clap::ValueEnum)]
+enum MetadataArg {
+    Sidecar,
+    Manifest,
+}
+
+impl From<&MetadataArg> for tsc_texture_extractor::metadata::Metadata {
+    fn from(metadata: &MetadataArg) -> Self {
+        match metadata {
+            MetadataArg::Sidecar => tsc_texture_extractor::metadata::Metadata::Sidecar,
+            MetadataArg::Manifest => tsc_texture_extractor::metadata::Metadata::Manifest,
+        }
+    }
+}
+
+#[derive(Clone, clap::ValueEnum)]
+enum FormatArg {
+    Png,
+    Tga,
+    #[clap(name = "webp")]
+    WebP,
+    Qoi,
+}
+
+impl From<&FormatArg> for tsc_texture_extractor::OutputFormat {
+    fn from(format: &FormatArg) -> Self {
+        match format {
+            FormatArg::Png => tsc_texture_extractor::OutputFormat::Png,
+            FormatArg::Tga => tsc_texture_extractor::OutputFormat::Tga,
+            FormatArg::WebP => tsc_texture_extractor::OutputFormat::WebP,
+            FormatArg::Qoi => tsc_texture_extractor::OutputFormat::Qoi,
+        }
+    }
+}
+
+#[derive(clap::Args)]
+struct ExtractArgs {
+    #[arg(
+        long,
+        help = "Skip textures that fail to decode, dump their raw bytes and write a failure report"
+    )]
+    keep_going: bool,
+    #[arg(long, help = "Also write every mipmap level as name.mipN.png")]
+    mipmaps: bool,
+    #[arg(
+        long,
+        help = "Write texture metadata as name.json next to each PNG or as one manifest.json"
+    )]
+    metadata: Option<MetadataArg>,
+    #[arg(
+        long,
+        help = "Read lines of \"specular <name>\", \"alpha <name>\", \"specular id:<id>\" or \"alpha id:<id>\" that override the built-in texture lists"
+    )]
+    texture_flags: Option<std::path::PathBuf>,
+    #[arg(
+        long,
+        help = "Decide whether alpha is specular or transparency from its statistics instead of the built-in lists"
+    )]
+    classify_alpha: bool,
+    #[arg(
+        long,
+        value_enum,
+        default_value = "png",
+        help = "Image format of the extracted textures"
+    )]
+    format: FormatArg,
+    #[arg(
+        long,
+        help = "Write BC1, BC2 and CMPR textures as DDS with their mipmaps, without recompressing them"
+    )]
+    dds: bool,
+    #[arg(
+        long,
+        conflicts_with_all = ["format", "dds"],
+        help = "Write C4 and C8 textures as 4-bit or 8-bit palette PNGs that keep the original palette"
+    )]
+    indexed: bool,
+    #[arg(
+        long,
+        help = "Write the palette of every C4 and C8 texture as .act, .gpl and .pal, and group identical palettes in palettes.txt"
+    )]
+    palettes: bool,
+    #[arg(
+        long,
+        help = "Number of textures to decode and save at once, defaults to the number of cores"
+    )]
+    jobs: Option<usize>,
+    #[command(flatten)]
+    filter: FilterArgs,
+    #[command(flatten)]
+    disc: DiscArgs,
+}
+
+#[derive(clap::Args)]
+struct FilterArgs {
+    #[arg(
+        long = "name",
+        value_name = "GLOB",
+        help = "Only extract textures whose name matches this glob, can be repeated"
+    )]
+    name_globs: Vec<glob::Pattern>,
+    #[arg(
+        long = "name-regex",
+        value_name = "REGEX",
+        help = "Only extract textures whose name matches this regular expression, can be repeated"
+    )]
+    name_regexes: Vec<regex::Regex>,
+    #[arg(
+        long = "id",
+        value_name = "IDS",
+        value_delimiter = ',',
+        help = "Only extract the textures with these ids"
+    )]
+    ids: Vec<u32>,
+    #[arg(
+        long = "texture-type",
+        value_name = "TYPES",
+        value_delimiter = ',',
+        value_parser = parse_texture_type,
+        help = "Only extract the textures with these type codes, in decimal or 0x hex"
+    )]
+    texture_types: Vec<u8>,
+    #[arg(
+        long = "texture-format",
+        value_name = "FORMATS",
+        value_delimiter = ',',
+        help = "Only extract the textures stored in these formats, as shown by the list command"
+    )]
+    texture_formats: Vec<String>,
+    #[arg(long, help = "Only extract textures at least this wide")]
+    min_width: Option<usize>,
+    #[arg(long, help = "Only extract textures at least this high")]
+    min_height: Option<usize>,
+    #[arg(long, help = "Only extract textures at most this wide")]
+    max_width: Option<usize>,
+    #[arg(long, help = "Only extract textures at most this high")]
+    max_height: Option<usize>,
+}
+
+impl From<&FilterArgs> for tsc_texture_extractor::filter::Filter {
+    fn from(filter: &FilterArgs) -> Self {
+        tsc_texture_extractor::filter::Filter {
+            name_globs: filter.name_globs.clone(),
+            name_regexes: filter.name_regexes.clone(),
+            ids: filter.ids.clone(),
+            texture_types: filter.texture_types.clone(),
+            formats: filter.texture_formats.clone(),
+            min_width: filter.min_width,
+            min_height: filter.min_height,
+            max_width: filter.max_width,
+            max_height: filter.max_height,
+        }
+    }
+}
+
+#[derive(clap::Args)]
+struct DiscArgs {
+    #[arg(
+        long,
+        help = "Path of the archive inside a disc image, found from the known archive names if not given"
+    )]
+    disc_path: Option<String>,
+    #[arg(
+        long,
+        help = "File holding the 16 byte Wii common key, in binary or hex, to decrypt Wii disc images"
+    )]
+    common_key: Option<std::path::PathBuf>,
+}
+
+fn parse_texture_type(text: &str) -> Result<u8, std::num::ParseIntError> {
+    match text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+        Some(hex) => u8::from_str_radix(hex, 16),
+        None => text.parse(),
+    }
+}
+
 #[allow(clippy::enum_variant_names)]
 #[derive(clap::Subcommand)]
 enum CliCommands {
+    #[command(about = "Detect the game and platform of an archive and extract its textures")]
+    Extract {
+        archive_path: std::path::PathBuf,
+        output_path: std::path::PathBuf,
+        #[arg(long, help = "Override the detected game")]
+        game: Option<GameArg>,
+        #[arg(long, help = "Override the detected platform")]
+        platform: Option<PlatformArg>,
+        #[command(flatten)]
+        options: ExtractArgs,
+    },
+    #[command(about = "Extract every entry of an archive unmodified as name.id.bin")]
+    ExtractRaw {
+        archive_path: std::path::PathBuf,
+        output_path: std::path::PathBuf,
+        #[arg(long, help = "Override the detected endianness of the entry list")]
+        endianness: Option<EndiannessArg>,
+        #[arg(
+            long,
+            help = "Game whose texture headers are read by the type, format and size filters"
+        )]
+        game: Option<GameArg>,
+        #[arg(
+            long,
+            help = "Platform whose texture headers are read by the type, format and size filters"
+        )]
+        platform: Option<PlatformArg>,
+        #[command(flatten)]
+        filter: FilterArgs,
+        #[command(flatten)]
+        disc: DiscArgs,
+    },
+    #[command(about = "Check that unpacking and repacking an archive gives back identical bytes")]
+    CheckRepack {
+        archive_path: std::path::PathBuf,
+        #[arg(long, help = "Override the detected endianness of the entry list")]
+        endianness: Option<EndiannessArg>,
+        #[command(flatten)]
+        disc: DiscArgs,
+    },
+    #[command(about = "Replace a texture in an archive with a PNG, re-encoded into the texture's original format")]
+    Replace {
+        archive_path: std::path::PathBuf,
+        entry_name: String,
+        png_path: std::path::PathBuf,
+        #[arg(long, help = "Write the archive to this path instead of overwriting it")]
+        output: Option<std::path::PathBuf>,
+        #[arg(
+            long,
+            help = "Grayscale image to store in the alpha channel, like the \"name specular.png\" written by extract"
+        )]
+        specular: Option<std::path::PathBuf>,
+        #[arg(long, help = "Override the detected game")]
+        game: Option<GameArg>,
+        #[arg(long, help = "Override the detected platform")]
+        platform: Option<PlatformArg>,
+    },
+    #[command(about = "Report textures where the alpha classifier disagrees with the built-in specular lists")]
+    Audit {
+        archive_path: std::path::PathBuf,
+        #[arg(long, help = "Override the detected game")]
+        game: Option<GameArg>,
+        #[arg(long, help = "Override the detected platform")]
+        platform: Option<PlatformArg>,
+        #[arg(
+            long,
+            help = "Read lines of \"specular <name>\", \"alpha <name>\", \"specular id:<id>\" or \"alpha id:<id>\" that override the built-in texture lists"
+        )]
+        texture_flags: Option<std::path::PathBuf>,
+        #[command(flatten)]
+        disc: DiscArgs,
+    },
+    #[command(about = "List the entries of an archive without decoding them")]
+    List {
+        archive_path: std::path::PathBuf,
+        #[arg(long, help = "Override the detected game")]
+        game: Option<GameArg>,
+        #[arg(long, help = "Override the detected platform")]
+        platform: Option<PlatformArg>,
+        #[arg(long, help = "Print the entries as JSON")]
+        json: bool,
+        #[arg(
+            long,
+            help = "Read lines of \"specular <name>\", \"alpha <name>\", \"specular id:<id>\" or \"alpha id:<id>\" that override the built-in texture lists"
+        )]
+        texture_flags: Option<std::path::PathBuf>,
+        #[command(flatten)]
+        disc: DiscArgs,
+    },
+    #[command(about = "List the archive files inside a PlayStation 2, GameCube, Wii or Xbox disc image")]
+    ListDisc {
+        image_path: std::path::PathBuf,
+        #[arg(long, help = "List every file instead of only the .arc archives")]
+        all: bool,
+        #[arg(
+            long,
+            help = "File holding the 16 byte Wii common key, in binary or hex, to decrypt Wii disc images"
+        )]
+        common_key: Option<std::path::PathBuf>,
+    },
     TheSims {
         console: Console0,
         datasets_path: std::path::PathBuf,
         output_path: std::path::PathBuf,
+        #[command(flatten)]
+        options: ExtractArgs,
     },
     TheSimsRle {
         console: Console0,
         rletextures_path: std::path::PathBuf,
         output_path: std::path::PathBuf,
+        #[command(flatten)]
+        options: ExtractArgs,
     },
     TheSimsBustinOut {
         console: Console0,
         textures_path: std::path::PathBuf,
         output_path: std::path::PathBuf,
+        #[command(flatten)]
+        options: ExtractArgs,
     },
     TheSimsBustinOutRle {
         console: Console0,
         rletextures_path: std::path::PathBuf,
         output_path: std::path::PathBuf,
+        #[command(flatten)]
+        options: ExtractArgs,
     },
     TheUrbz {
         console: Console0,
         textures_path: std::path::PathBuf,
         output_path: std::path::PathBuf,
+        #[command(flatten)]
+        options: ExtractArgs,
     },
     #[clap(name = "the-sims-2")]
     TheSims2 {
         console: Console0,
         textures_path: std::path::PathBuf,
         output_path: std::path::PathBuf,
+        #[command(flatten)]
+        options: ExtractArgs,
     },
     #[clap(name = "the-sims-2-pets")]
     TheSims2Pets {
         console: Console1,
         textures_path: std::path::PathBuf,
         output_path: std::path::PathBuf,
+        #[command(flatten)]
+        options: ExtractArgs,
     },
     #[clap(name = "the-sims-2-castaway")]
     TheSims2Castaway {
         console: Console2,
         textures_path: std::path::PathBuf,
         output_path: std::path::PathBuf,
+        #[command(flatten)]
+        options: ExtractArgs,
     },
     #[clap(name = "the-sims-3")]
     TheSims3 {
         console: Console3,
         textures_path: std::path::PathBuf,
         output_path: std::path::PathBuf,
+        #[command(flatten)]
+        options: ExtractArgs,
     },
 }
 
-fn main() {
-    use clap::Parser;
-    let cli = Cli::parse();
+fn read_archive(archive_path: &std::path::Path) -> tsc_texture_extractor::Result<memmap2::Mmap> {
+    tsc_texture_extractor::map_archive(archive_path)
+}
+
+fn open_image(path: &std::path::Path) -> tsc_texture_extractor::Result<image::DynamicImage> {
+    image::open(path).map_err(|source| Error::Image {
+        path: path.to_owned(),
+        source,
+    })
+}
+
+fn merge_alpha(image: &mut image::RgbaImage, alpha: &image::GrayImage) -> tsc_texture_extractor::Result<()> {
+    if image.dimensions() != alpha.dimensions() {
+        return Err(Error::DimensionMismatch {
+            expected_width: image.width() as usize,
+            expected_height: image.height() as usize,
+            width: alpha.width() as usize,
+            height: alpha.height() as usize,
+        });
+    }
+
+    for (pixel, alpha) in image.pixels_mut().zip(alpha.pixels()) {
+        pixel.0[3] = alpha.0[0];
+    }
+
+    Ok(())
+}
+
+fn load_texture_flags(
+    path: Option<&std::path::Path>,
+) -> tsc_texture_extractor::Result<tsc_texture_extractor::texture_flags::TextureFlags> {
+    path.map_or(
+        Ok(Default::default()),
+        tsc_texture_extractor::texture_flags::TextureFlags::load,
+    )
+}
 
-    match &cli.command {
+fn load_common_key(path: Option<&std::path::Path>) -> tsc_texture_extractor::Result<Option<[u8; 16]>> {
+    path.map(tsc_texture_extractor::wii::load_common_key).transpose()
+}
+
+fn load_archive(
+    archive_path: &std::path::Path,
+    disc: &DiscArgs,
+    game: Option<Game>,
+) -> tsc_texture_extractor::Result<tsc_texture_extractor::Archive> {
+    let common_key = load_common_key(disc.common_key.as_deref())?;
+    let archive =
+        tsc_texture_extractor::load_archive(archive_path, disc.disc_path.as_deref(), common_key.as_ref(), game)?;
+    if let Some(disc_path) = &archive.disc_path {
+        eprintln!("reading {disc_path} from {}", archive_path.display());
+    }
+
+    Ok(archive)
+}
+
+fn detect(
+    archive: &[u8],
+    archive_path: &std::path::Path,
+    game: Option<&GameArg>,
+    platform: Option<&PlatformArg>,
+) -> tsc_texture_extractor::Result<tsc_texture_extractor::detect::Detection> {
+    tsc_texture_extractor::detect::detect(archive, game.map(Game::from), platform.map(Platform::from))
+        .ok_or(Error::UnrecognizedArchive.in_archive(archive_path))
+}
+
+fn game_and_platform(
+    archive: &[u8],
+    archive_path: &std::path::Path,
+    game: Option<&GameArg>,
+    platform: Option<&PlatformArg>,
+) -> tsc_texture_extractor::Result<(Game, Platform)> {
+    match (game, platform) {
+        (Some(game), Some(platform)) => Ok((game.into(), platform.into())),
+        _ => {
+            let detection = detect(archive, archive_path, game, platform)?;
+            eprintln!("detected {detection}");
+            Ok((detection.game, detection.platform))
+        }
+    }
+}
+
+fn arc_endianness(
+    archive: &[u8],
+    archive_path: &std::path::Path,
+    endianness: Option<&EndiannessArg>,
+) -> tsc_texture_extractor::Result<tsc_texture_extractor::Endianness> {
+    match endianness {
+        Some(endianness) => Ok(endianness.into()),
+        None => tsc_texture_extractor::arc::detect_endianness(archive)
+            .ok_or(Error::UnrecognizedEndianness.in_archive(archive_path)),
+    }
+}
+
+#[derive(serde::Serialize)]
+struct ListedEntry<'a> {
+    name: &'a str,
+    id: u32,
+    offset: usize,
+    size: usize,
+    texture_type: Option<u8>,
+    format: Option<&'static str>,
+    width: Option<usize>,
+    height: Option<usize>,
+    palette_count: Option<usize>,
+    mip_count: Option<usize>,
+    specular: bool,
+    error: Option<String>,
+}
+
+fn list(
+    archive: &[u8],
+    archive_path: &std::path::Path,
+    game: Game,
+    platform: Platform,
+    json: bool,
+    texture_flags: &tsc_texture_extractor::texture_flags::TextureFlags,
+) -> tsc_texture_extractor::Result<()> {
+    let entries =
+        tsc_texture_extractor::list_entries(archive, game, platform).map_err(|error| error.in_archive(archive_path))?;
+
+    let listed_entries = entries
+        .iter()
+        .map(|entry| {
+            let info = tsc_texture_extractor::texture_info(entry.bytes, game, platform);
+            let (info, error) = match info {
+                Ok(info) => (Some(info), None),
+                Err(error) => (None, Some(error.to_string())),
+            };
+            ListedEntry {
+                name: &entry.name,
+                id: entry.id,
+                offset: entry.offset,
+                size: entry.bytes.len(),
+                texture_type: info.as_ref().and_then(|info| info.texture_type),
+                format: info.as_ref().map(|info| info.format.name()),
+                width: info.as_ref().map(|info| info.width),
+                height: info.as_ref().map(|info| info.height),
+                palette_count: info.as_ref().map(|info| info.palette_count),
+                mip_count: info.as_ref().map(|info| {
+                    tsc_texture_extractor::texture::check_mip_chain(entry.bytes, info).map_or(1, |levels| levels.len())
+                }),
+                specular: texture_flags
+                    .specular(entry)
+                    .unwrap_or_else(|| tsc_texture_extractor::is_specular(entry, game)),
+                error,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    if json {
+        let json = serde_json::to_string_pretty(&listed_entries).expect("entries serialize to JSON");
+        println!("{json}");
+        return Ok(());
+    }
+
+    let name_width = listed_entries.iter().map(|entry| entry.name.len()).max().unwrap_or(0).max(4);
+    println!(
+        "{:name_width$}  {:>10}  {:>10}  {:>10}  {:>4}  {:<6}  {:>10}  {:>7}  {:>4}  {:>8}",
+        "name", "id", "offset", "size", "type", "format", "dimensions", "palette", "mips", "specular"
+    );
+    for entry in &listed_entries {
+        let texture_type = entry.texture_type.map_or("-".to_string(), |texture_type| format!("{texture_type:#04X}"));
+        let dimensions = match (entry.width, entry.height) {
+            (Some(width), Some(height)) => format!("{width}x{height}"),
+            _ => "-".to_string(),
+        };
+        let palette_count = entry.palette_count.map_or("-".to_string(), |palette_count| palette_count.to_string());
+        let mip_count = entry.mip_count.map_or("-".to_string(), |mip_count| mip_count.to_string());
+        print!(
+            "{:name_width$}  {:>10}  {:>#10X}  {:>10}  {:>4}  {:<6}  {:>10}  {:>7}  {:>4}  {:>8}",
+            entry.name,
+            entry.id,
+            entry.offset,
+            entry.size,
+            texture_type,
+            entry.format.unwrap_or("-"),
+            dimensions,
+            palette_count,
+            mip_count,
+            if entry.specular { "yes" } else { "no" }
+        );
+        match &entry.error {
+            Some(error) => println!("  {error}"),
+            None => println!(),
+        }
+    }
+
+    Ok(())
+}
+
+fn list_disc(
+    image: &[u8],
+    image_path: &std::path::Path,
+    all: bool,
+    common_key: Option<&[u8; 16]>,
+) -> tsc_texture_extractor::Result<()> {
+    let files = tsc_texture_extractor::disc::open(image, common_key)
+        .and_then(|image| tsc_texture_extractor::disc::files(image.as_ref()))
+        .map_err(|error| error.in_archive(image_path))?;
+    let files = files
+        .iter()
+        .filter(|file| all || tsc_texture_extractor::disc::is_archive(&file.path))
+        .collect::<Vec<_>>();
+
+    let path_width = files.iter().map(|file| file.path.len()).max().unwrap_or(0).max(4);
+    println!("{:path_width$}  {:>12}  {:>10}", "path", "offset", "size");
+    for file in files {
+        println!("{:path_width$}  {:>#12X}  {:>10}", file.path, file.offset, file.length);
+    }
+
+    Ok(())
+}
+
+fn audit(
+    archive: &[u8],
+    archive_path: &std::path::Path,
+    game: Game,
+    platform: Platform,
+    texture_flags: &tsc_texture_extractor::texture_flags::TextureFlags,
+) -> tsc_texture_extractor::Result<()> {
+    let entries =
+        tsc_texture_extractor::list_entries(archive, game, platform).map_err(|error| error.in_archive(archive_path))?;
+
+    let mut agreed_count = 0;
+    let mut disagreed_count = 0;
+    let mut failed_count = 0;
+    for entry in &entries {
+        let Ok(texture) = tsc_texture_extractor::decode_entry(entry, game, platform) else {
+            failed_count += 1;
+            continue;
+        };
+
+        let alpha_usage = tsc_texture_extractor::classify_alpha(&texture.image);
+        let specular = texture_flags.specular(entry).unwrap_or(texture.specular);
+        let listed = if specular { "specular" } else { "not specular" };
+        match alpha_usage {
+            tsc_texture_extractor::AlphaUsage::Unused => agreed_count += 1,
+            alpha_usage if (alpha_usage == tsc_texture_extractor::AlphaUsage::Specular) == specular => {
+                agreed_count += 1
+            }
+            alpha_usage => {
+                disagreed_count += 1;
+                println!(
+                    "{} (id {}): listed as {listed}, alpha looks like {alpha_usage}",
+                    entry.name, entry.id
+                );
+            }
+        }
+    }
+
+    println!("agreed: {agreed_count}");
+    println!("disagreed: {disagreed_count}");
+    println!("failed to decode: {failed_count}");
+
+    Ok(())
+}
+
+fn extract(
+    archive: &[u8],
+    archive_path: &std::path::Path,
+    output_path: &std::path::Path,
+    game: Game,
+    platform: Platform,
+    options: &ExtractArgs,
+) -> tsc_texture_extractor::Result<()> {
+    let options = tsc_texture_extractor::ExtractOptions {
+        keep_going: options.keep_going,
+        mipmaps: options.mipmaps,
+        metadata: options.metadata.as_ref().map(Into::into),
+        source_archive: Some(archive_path.to_owned()),
+        texture_flags: load_texture_flags(options.texture_flags.as_deref())?,
+        classify_alpha: options.classify_alpha,
+        format: (&options.format).into(),
+        dds: options.dds,
+        indexed: options.indexed,
+        palettes: options.palettes,
+        jobs: options.jobs,
+        filter: (&options.filter).into(),
+    };
+
+    let report = tsc_texture_extractor::extract_textures(archive, output_path, game, platform, &options)
+        .map_err(|error| error.in_archive(archive_path))?;
+
+    for warning in &report.warnings {
+        eprintln!(
+            "warning: {} (id {}): {}, keeping only the top level",
+            warning.name, warning.id, warning.error
+        );
+    }
+
+    if options.keep_going {
+        println!("extracted: {}", report.extracted);
+        println!("failed: {}", report.failures.len());
+        for failure in &report.failures {
+            println!("  {} (id {}): {}", failure.name, failure.id, failure.error);
+        }
+
+        report.write(&output_path.join("failures.txt"))?;
+    }
+
+    Ok(())
+}
+
+fn run(command: &CliCommands) -> tsc_texture_extractor::Result<()> {
+    let (game, platform, archive_path, output_path, options) = match command {
+        CliCommands::Extract {
+            archive_path,
+            output_path,
+            game,
+            platform,
+            options,
+        } => {
+            let archive = load_archive(archive_path, &options.disc, game.as_ref().map(Game::from))?;
+            let (game, platform) = game_and_platform(&archive, archive_path, game.as_ref(), platform.as_ref())?;
+
+            return extract(&archive, archive_path, output_path, game, platform, options);
+        }
+        CliCommands::ExtractRaw {
+            archive_path,
+            output_path,
+            endianness,
+            game,
+            platform,
+            filter,
+            disc,
+        } => {
+            let archive = load_archive(archive_path, disc, game.as_ref().map(Game::from))?;
+            let endianness = arc_endianness(&archive, archive_path, endianness.as_ref())?;
+
+            let filter = tsc_texture_extractor::filter::Filter::from(filter);
+            let headers = if filter.needs_texture_info() {
+                Some(game_and_platform(
+                    &archive,
+                    archive_path,
+                    game.as_ref(),
+                    platform.as_ref(),
+                )?)
+            } else {
+                None
+            };
+            let count = tsc_texture_extractor::extract_raw_entries(&archive, output_path, endianness, &filter, headers)
+                .map_err(|error| error.in_archive(archive_path))?;
+            println!("extracted: {count}");
+
+            return Ok(());
+        }
+        CliCommands::CheckRepack {
+            archive_path,
+            endianness,
+            disc,
+        } => {
+            let archive = load_archive(archive_path, disc, None)?;
+            let endianness = arc_endianness(&archive, archive_path, endianness.as_ref())?;
+
+            tsc_texture_extractor::arc::check_repack(&archive, endianness)
+                .map_err(|error| error.in_archive(archive_path))?;
+            println!("repacked archive is identical ({} bytes)", archive.len());
+
+            return Ok(());
+        }
+        CliCommands::Replace {
+            archive_path,
+            entry_name,
+            png_path,
+            output,
+            specular,
+            game,
+            platform,
+        } => {
+            let archive = read_archive(archive_path)?;
+            if tsc_texture_extractor::disc::is_disc(&archive).map_err(|error| error.in_archive(archive_path))? {
+                return Err(Error::DiscImageNotWritable.in_archive(archive_path));
+            }
+            let (game, platform) = game_and_platform(&archive, archive_path, game.as_ref(), platform.as_ref())?;
+
+            let input = open_image(png_path)?;
+            let mut image = input.to_rgba8();
+            match specular {
+                Some(specular_path) => {
+                    let alpha = open_image(specular_path)?.to_luma8();
+                    merge_alpha(&mut image, &alpha).map_err(|error| error.in_archive(specular_path))?;
+                }
+                // Without an alpha channel in the input, the texture keeps its current alpha or specular map.
+                None if !input.color().has_alpha() => {
+                    let texture = tsc_texture_extractor::find_texture(&archive, game, platform, entry_name);
+                    if let Some(texture) =
+                        texture.ok().filter(|texture| texture.image.dimensions() == image.dimensions())
+                    {
+                        for (pixel, texture_pixel) in image.pixels_mut().zip(texture.image.pixels()) {
+                            pixel.0[3] = texture_pixel.0[3];
+                        }
+                    }
+                }
+                None => {}
+            }
+
+            let replaced = tsc_texture_extractor::replace_texture(&archive, game, platform, entry_name, &image)
+                .map_err(|error| error.in_archive(archive_path))?;
+            drop(archive);
+
+            let output_path = output.as_ref().unwrap_or(archive_path);
+            std::fs::write(output_path, replaced).map_err(|error| Error::io(output_path, error))?;
+            println!("replaced \"{entry_name}\" in {}", output_path.display());
+
+            return Ok(());
+        }
+        CliCommands::Audit {
+            archive_path,
+            game,
+            platform,
+            texture_flags,
+            disc,
+        } => {
+            let texture_flags = load_texture_flags(texture_flags.as_deref())?;
+            let archive = load_archive(archive_path, disc, game.as_ref().map(Game::from))?;
+            let (game, platform) = game_and_platform(&archive, archive_path, game.as_ref(), platform.as_ref())?;
+
+            return audit(&archive, archive_path, game, platform, &texture_flags);
+        }
+        CliCommands::List {
+            archive_path,
+            game,
+            platform,
+            json,
+            texture_flags,
+            disc,
+        } => {
+            let texture_flags = load_texture_flags(texture_flags.as_deref())?;
+            let archive = load_archive(archive_path, disc, game.as_ref().map(Game::from))?;
+            let (game, platform) = game_and_platform(&archive, archive_path, game.as_ref(), platform.as_ref())?;
+
+            return list(&archive, archive_path, game, platform, *json, &texture_flags);
+        }
+        CliCommands::ListDisc {
+            image_path,
+            all,
+            common_key,
+        } => {
+            let common_key = load_common_key(common_key.as_deref())?;
+            let image = read_archive(image_path)?;
+
+            return list_disc(&image, image_path, *all, common_key.as_ref());
+        }
         CliCommands::TheSims {
             console,
             datasets_path,
             output_path,
-        } => match console {
-            Console0::PlayStation2 => the_sims::extract_playstation_2_textures(datasets_path, output_path),
-            Console0::GameCube => the_sims::extract_gamecube_textures(datasets_path, output_path),
-            Console0::Xbox => the_sims::extract_xbox_textures(datasets_path, output_path),
-        },
+            options,
+        } => (Game::TheSims, console.into(), datasets_path, output_path, options),
         CliCommands::TheSimsRle {
             console,
             rletextures_path,
             output_path,
-        } => match console {
-            Console0::PlayStation2 => the_sims::extract_rle_textures(rletextures_path, output_path, Endianness::Little),
-            Console0::GameCube => the_sims::extract_rle_textures(rletextures_path, output_path, Endianness::Big),
-            Console0::Xbox => the_sims::extract_rle_textures(rletextures_path, output_path, Endianness::Little),
-        },
+            options,
+        } => (Game::TheSimsRle, console.into(), rletextures_path, output_path, options),
         CliCommands::TheSimsBustinOut {
             console,
             textures_path,
             output_path,
-        } => match console {
-            Console0::PlayStation2 => the_sims_bustin_out::extract_playstation_2_textures(textures_path, output_path),
-            Console0::GameCube => the_sims_bustin_out::extract_gamecube_textures(textures_path, output_path),
-            Console0::Xbox => the_sims_bustin_out::extract_xbox_textures(textures_path, output_path),
-        },
+            options,
+        } => (
+            Game::TheSimsBustinOut,
+            console.into(),
+            textures_path,
+            output_path,
+            options,
+        ),
         CliCommands::TheSimsBustinOutRle {
             console,
             rletextures_path,
             output_path,
-        } => match console {
-            Console0::PlayStation2 | Console0::Xbox => {
-                the_sims_bustin_out::extract_rle_textures(rletextures_path, output_path, Endianness::Little)
-            }
-            Console0::GameCube => {
-                the_sims_bustin_out::extract_rle_textures(rletextures_path, output_path, Endianness::Big)
-            }
-        },
+            options,
+        } => (
+            Game::TheSimsBustinOutRle,
+            console.into(),
+            rletextures_path,
+            output_path,
+            options,
+        ),
         CliCommands::TheUrbz {
             console,
             textures_path,
             output_path,
-        } => match console {
-            Console0::PlayStation2 => the_urbz::extract_playstation_2_textures(textures_path, output_path),
-            Console0::GameCube => the_urbz::extract_gamecube_textures(textures_path, output_path),
-            Console0::Xbox => the_urbz::extract_xbox_textures(textures_path, output_path),
-        },
+            options,
+        } => (Game::TheUrbz, console.into(), textures_path, output_path, options),
         CliCommands::TheSims2 {
             console,
             textures_path,
             output_path,
-        } => match console {
-            Console0::PlayStation2 => {
-                the_sims_2::extract_playstation_2_textures(textures_path, output_path, &the_sims_2::SPECULAR_FILE_NAMES)
-            }
-            Console0::GameCube => {
-                the_sims_2::extract_gamecube_textures(textures_path, output_path, &the_sims_2::SPECULAR_FILE_NAMES)
-            }
-            Console0::Xbox => the_sims_2::extract_xbox_textures(textures_path, output_path),
-        },
+            options,
+        } => (Game::TheSims2, console.into(), textures_path, output_path, options),
         CliCommands::TheSims2Pets {
             console,
             textures_path,
             output_path,
-        } => match console {
-            Console1::PlayStation2 => the_sims_2::extract_playstation_2_textures(
-                textures_path,
-                output_path,
-                &the_sims_2_pets::SPECULAR_FILE_NAMES,
-            ),
-            Console1::GameCube | Console1::Wii => {
-                the_sims_2::extract_gamecube_textures(textures_path, output_path, &the_sims_2_pets::SPECULAR_FILE_NAMES)
-            }
-        },
+            options,
+        } => (Game::TheSims2Pets, console.into(), textures_path, output_path, options),
         CliCommands::TheSims2Castaway {
             console,
             textures_path,
             output_path,
-        } => match console {
-            Console2::PlayStation2 => the_sims_2::extract_playstation_2_textures(
-                textures_path,
-                output_path,
-                &the_sims_2_castaway::SPECULAR_FILE_NAMES,
-            ),
-            Console2::Wii => the_sims_2::extract_gamecube_textures(
-                textures_path,
-                output_path,
-                &the_sims_2_castaway::SPECULAR_FILE_NAMES,
-            ),
-        },
+            options,
+        } => (
+            Game::TheSims2Castaway,
+            console.into(),
+            textures_path,
+            output_path,
+            options,
+        ),
         CliCommands::TheSims3 {
             console,
             textures_path,
             output_path,
-        } => match console {
-            Console3::Wii => the_sims_3::extract_textures(textures_path, output_path),
-        },
+            options,
+        } => (Game::TheSims3, console.into(), textures_path, output_path, options),
+    };
+
+    let archive = load_archive(archive_path, &options.disc, Some(game))?;
+    extract(&archive, archive_path, output_path, game, platform, options)
+}
+
+fn main() {
+    use clap::Parser;
+    let cli = Cli::parse();
+
+    if let Err(error) = run(&cli.command) {
+        eprintln!("error: {error}");
+        std::process::exit(1);
     }
 }
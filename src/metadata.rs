@@ -0,0 +1,53 @@
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Metadata {
+    Sidecar,
+    Manifest,
+}
+
+#[derive(serde::Serialize)]
+pub struct TextureMetadata {
+    pub name: String,
+    pub id: u32,
+    pub texture_type: Option<u8>,
+    pub format: &'static str,
+    pub bit_count: u8,
+    pub width: usize,
+    pub height: usize,
+    pub palette_count: usize,
+    pub mip_count: usize,
+    pub game: String,
+    pub platform: String,
+    pub source_archive: Option<String>,
+    pub specular: bool,
+}
+
+impl TextureMetadata {
+    pub fn new(
+        texture: &crate::Texture,
+        mip_count: usize,
+        game: crate::Game,
+        platform: crate::Platform,
+        source_archive: Option<&std::path::Path>,
+    ) -> TextureMetadata {
+        TextureMetadata {
+            name: texture.name.clone(),
+            id: texture.id,
+            texture_type: texture.info.texture_type,
+            format: texture.info.format.name(),
+            bit_count: texture.info.format.bit_count(),
+            width: texture.info.width,
+            height: texture.info.height,
+            palette_count: texture.info.palette_count,
+            mip_count,
+            game: game.to_string(),
+            platform: platform.to_string(),
+            source_archive: source_archive.map(|path| path.display().to_string()),
+            specular: texture.specular,
+        }
+    }
+}
+
+pub fn write<T: serde::Serialize>(metadata: &T, path: &std::path::Path) -> crate::Result<()> {
+    let json = serde_json::to_vec_pretty(metadata).map_err(|error| crate::Error::io(path, error.into()))?;
+    std::fs::write(path, json).map_err(|error| crate::Error::io(path, error))
+}
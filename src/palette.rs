@@ -0,0 +1,102 @@
+pub fn act(colors: &[[u8; 4]]) -> Vec<u8> {
+    let mut act = vec![0; 256 * 3];
+    for (i, [r, g, b, _]) in colors.iter().take(256).enumerate() {
+        act[i * 3..(i * 3) + 3].copy_from_slice(&[*r, *g, *b]);
+    }
+
+    let transparent_index = colors.iter().position(|color| color[3] == 0).map_or(0xFFFF, |index| index as u16);
+    act.extend_from_slice(&(colors.len().min(256) as u16).to_be_bytes());
+    act.extend_from_slice(&transparent_index.to_be_bytes());
+    act
+}
+
+pub fn gpl(name: &str, colors: &[[u8; 4]]) -> String {
+    let mut gpl = format!("GIMP Palette\nName: {name}\nColumns: 16\n#\n");
+    for (i, [r, g, b, _]) in colors.iter().enumerate() {
+        gpl += &format!("{r:3} {g:3} {b:3}\tIndex {i}\n");
+    }
+
+    gpl
+}
+
+pub fn jasc_pal(colors: &[[u8; 4]]) -> String {
+    let mut pal = format!("JASC-PAL\r\n0100\r\n{}\r\n", colors.len());
+    for [r, g, b, _] in colors {
+        pal += &format!("{r} {g} {b}\r\n");
+    }
+
+    pal
+}
+
+pub fn write(name: &str, colors: &[[u8; 4]], output_path: &std::path::Path) -> crate::Result<()> {
+    let files = [
+        ("act", act(colors)),
+        ("gpl", gpl(name, colors).into_bytes()),
+        ("pal", jasc_pal(colors).into_bytes()),
+    ];
+    for (extension, contents) in files {
+        let path = output_path.join(format!("{name}.{extension}"));
+        std::fs::write(&path, contents).map_err(|error| crate::Error::io(&path, error))?;
+    }
+
+    Ok(())
+}
+
+#[derive(Default)]
+pub struct PaletteGroups {
+    groups: Vec<(usize, Vec<String>)>,
+    group_indices: std::collections::HashMap<Vec<u8>, usize>,
+}
+
+impl PaletteGroups {
+    pub fn add(&mut self, palette: &[u8], color_count: usize, name: &str) {
+        let index = *self.group_indices.entry(palette.to_vec()).or_insert_with(|| {
+            self.groups.push((color_count, Vec::new()));
+            self.groups.len() - 1
+        });
+        self.groups[index].1.push(name.to_owned());
+    }
+
+    pub fn write(&self, path: &std::path::Path) -> crate::Result<()> {
+        let texture_count = self.groups.iter().map(|(_, names)| names.len()).sum::<usize>();
+        let mut report = format!(
+            "indexed textures: {texture_count}\nunique palettes: {}\n",
+            self.groups.len()
+        );
+        for (color_count, names) in self.groups.iter().filter(|(_, names)| names.len() > 1) {
+            report += &format!("{color_count} colors, {} textures: {}\n", names.len(), names.join(", "));
+        }
+
+        std::fs::write(path, report).map_err(|error| crate::Error::io(path, error))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    const COLORS: [[u8; 4]; 3] = [[255, 0, 0, 255], [0, 128, 0, 0], [1, 2, 3, 0]];
+
+    #[test]
+    fn act() {
+        let act = super::act(&COLORS);
+        assert_eq!(act.len(), 772);
+        assert_eq!(&act[..9], [255, 0, 0, 0, 128, 0, 1, 2, 3]);
+        assert_eq!(&act[768..], [0, 3, 0, 1]);
+    }
+
+    #[test]
+    fn act_without_transparency() {
+        assert_eq!(&super::act(&COLORS[..1])[768..], [0, 1, 0xFF, 0xFF]);
+    }
+
+    #[test]
+    fn gpl_and_jasc_pal() {
+        assert_eq!(
+            super::gpl("wall", &COLORS[..2]),
+            "GIMP Palette\nName: wall\nColumns: 16\n#\n255   0   0\tIndex 0\n  0 128   0\tIndex 1\n"
+        );
+        assert_eq!(
+            super::jasc_pal(&COLORS[..2]),
+            "JASC-PAL\r\n0100\r\n2\r\n255 0 0\r\n0 128 0\r\n"
+        );
+    }
+}
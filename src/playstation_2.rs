@@ -1,102 +1,237 @@
-pub fn decode_rgb5(bytes: &[u8], width: usize, height: usize) -> image::RgbaImage {
-    let mut image = image::RgbaImage::new(width as _, height as _);
+pub fn decode_rgb5(bytes: &[u8], width: usize, height: usize) -> crate::Result<image::RgbaImage> {
+    let bytes = crate::bytes::slice(bytes, 0, width * height * 2)?;
 
-    let mut i = 0;
-    for y in 0..height {
-        for x in 0..width {
-            let pixel_index = i * 2;
-
-            let bits = u16::from_le_bytes(bytes[pixel_index..pixel_index + 2].try_into().unwrap());
+    let mut pixels = Vec::with_capacity(width * height * 4);
+    for row in crate::bytes::rows_bottom_up(bytes, width * 2) {
+        for pixel in row.chunks_exact(2) {
+            let bits = u16::from_le_bytes([pixel[0], pixel[1]]);
 
             let r = ((bits & 0b0000_0000_0001_1111) << 3) as u8;
             let b = (((bits & 0b0111_1100_0000_0000) >> 10) << 3) as u8;
             let g = (((bits & 0b0000_0011_1110_0000) >> 5) << 3) as u8;
 
-            image.put_pixel(x as u32, y as u32, image::Rgba([r, g, b, 255]));
-            i += 1;
+            pixels.extend_from_slice(&[r, g, b, 255]);
         }
     }
 
-    image::imageops::flip_vertical(&image)
+    Ok(image::RgbaImage::from_raw(width as _, height as _, pixels).unwrap())
 }
 
-pub fn decode_rgb8(bytes: &[u8], width: usize, height: usize) -> image::RgbaImage {
-    let mut image = image::RgbaImage::new(width as _, height as _);
-
-    let mut i = 0;
-    for y in 0..height {
-        for x in 0..width {
-            let r = bytes[i];
-            let g = bytes[i + 1];
-            let b = bytes[i + 2];
-            image.put_pixel(x as u32, y as u32, image::Rgba([r, g, b, 255]));
-            i += 3;
+pub fn decode_rgb8(bytes: &[u8], width: usize, height: usize) -> crate::Result<image::RgbaImage> {
+    let bytes = crate::bytes::slice(bytes, 0, width * height * 3)?;
+
+    let mut pixels = Vec::with_capacity(width * height * 4);
+    for row in crate::bytes::rows_bottom_up(bytes, width * 3) {
+        for pixel in row.chunks_exact(3) {
+            pixels.extend_from_slice(&[pixel[0], pixel[1], pixel[2], 255]);
         }
     }
 
-    image::imageops::flip_vertical(&image)
+    Ok(image::RgbaImage::from_raw(width as _, height as _, pixels).unwrap())
+}
+
+pub fn decode_rgba8(bytes: &[u8], width: usize, height: usize) -> crate::Result<image::RgbaImage> {
+    let bytes = crate::bytes::slice(bytes, 0, width * height * 4)?;
+
+    let mut pixels = Vec::with_capacity(width * height * 4);
+    for row in crate::bytes::rows_bottom_up(bytes, width * 4) {
+        pixels.extend_from_slice(row);
+    }
+    for pixel in pixels.chunks_exact_mut(4) {
+        pixel[3] = pixel[3].saturating_add(pixel[3]);
+    }
+
+    Ok(image::RgbaImage::from_raw(width as _, height as _, pixels).unwrap())
 }
 
-pub fn decode_rgba8(bytes: &[u8], width: usize, height: usize) -> image::RgbaImage {
-    let mut image = image::RgbaImage::new(width as _, height as _);
-
-    let mut i = 0;
-    for y in 0..height {
-        for x in 0..width {
-            let pixel_index = i * 4;
-            let r = bytes[pixel_index];
-            let g = bytes[pixel_index + 1];
-            let b = bytes[pixel_index + 2];
-            let a = bytes[pixel_index + 3];
-            image.put_pixel(x as u32, y as u32, image::Rgba([r, g, b, a.saturating_mul(2)]));
-            i += 1;
+pub fn decode_palette(palette: &[u8], palette_count: usize) -> crate::Result<Vec<[u8; 4]>> {
+    let palette = crate::bytes::slice(palette, 0, palette_count * 4)?;
+    Ok(palette
+        .chunks_exact(4)
+        .map(|color| [color[0], color[1], color[2], color[3].saturating_mul(2)])
+        .collect())
+}
+
+pub fn decode_c4_indices(bytes: &[u8], width: usize, height: usize) -> crate::Result<image::GrayImage> {
+    let bytes = crate::bytes::slice(bytes, 0, (width / 2) * height)?;
+
+    let mut indices = Vec::with_capacity(width * height);
+    for y in (0..height).rev() {
+        for pair in &bytes[y * (width / 2)..(y + 1) * (width / 2)] {
+            indices.extend_from_slice(&[pair & 0b0000_1111, pair >> 4]);
+        }
+        if !width.is_multiple_of(2) {
+            indices.push(0);
         }
     }
 
-    image::imageops::flip_vertical(&image)
+    Ok(image::GrayImage::from_raw(width as _, height as _, indices).unwrap())
 }
 
-pub fn decode_c4(bytes: &[u8], width: usize, height: usize, palette: &[u8]) -> image::RgbaImage {
-    let mut image = image::RgbaImage::new(width as _, height as _);
-
-    let mut i = 0;
-    for y in 0..height {
-        for x in 0..width / 2 {
-            let palette_index = usize::from(bytes[i] & 0b0000_1111) * 4;
-            let r = palette[palette_index];
-            let g = palette[palette_index + 1];
-            let b = palette[palette_index + 2];
-            let a = palette[palette_index + 3];
-            image.put_pixel(x as u32 * 2, y as u32, image::Rgba([r, g, b, a.saturating_mul(2)]));
-
-            let palette_index = usize::from(bytes[i] >> 4) * 4;
-            let r = palette[palette_index];
-            let g = palette[palette_index + 1];
-            let b = palette[palette_index + 2];
-            let a = palette[palette_index + 3];
-            image.put_pixel(x as u32 * 2 + 1, y as u32, image::Rgba([r, g, b, a.saturating_mul(2)]));
-            i += 1;
+pub fn decode_c8_indices(bytes: &[u8], width: usize, height: usize) -> crate::Result<image::GrayImage> {
+    let bytes = crate::bytes::slice(bytes, 0, width * height)?;
+    let indices = crate::bytes::rows_bottom_up(bytes, width).flatten().copied().collect();
+    Ok(image::GrayImage::from_raw(width as _, height as _, indices).unwrap())
+}
+
+pub fn decode_c4(bytes: &[u8], width: usize, height: usize, palette: &[u8]) -> crate::Result<image::RgbaImage> {
+    let indices = decode_c4_indices(bytes, width, height)?;
+    let palette = decode_palette(palette, 16)?;
+    let mut image = crate::texture::expand(&indices, &palette);
+
+    // Only whole pairs are stored, so the last column of an odd width has no pixels and stays transparent.
+    if !width.is_multiple_of(2) {
+        for y in 0..height {
+            image.put_pixel(width as u32 - 1, y as u32, image::Rgba([0; 4]));
         }
     }
 
-    image::imageops::flip_vertical(&image)
+    Ok(image)
+}
+
+pub fn decode_c8(bytes: &[u8], width: usize, height: usize, palette: &[u8]) -> crate::Result<image::RgbaImage> {
+    let indices = decode_c8_indices(bytes, width, height)?;
+    let palette = decode_palette(palette, 256)?;
+    Ok(crate::texture::expand(&indices, &palette))
+}
+
+pub fn encode_rgb5(image: &image::RgbaImage) -> Vec<u8> {
+    let image = image::imageops::flip_vertical(image);
+
+    let mut bytes = Vec::new();
+    for pixel in image.pixels() {
+        let [r, g, b, a] = pixel.0;
+        let bits =
+            u16::from(r >> 3) | (u16::from(g >> 3) << 5) | (u16::from(b >> 3) << 10) | (u16::from(a >= 128) << 15);
+        bytes.extend_from_slice(&bits.to_le_bytes());
+    }
+
+    bytes
+}
+
+pub fn encode_rgb8(image: &image::RgbaImage) -> Vec<u8> {
+    let image = image::imageops::flip_vertical(image);
+
+    let mut bytes = Vec::new();
+    for pixel in image.pixels() {
+        bytes.extend_from_slice(&pixel.0[..3]);
+    }
+
+    bytes
+}
+
+pub fn encode_rgba8(image: &image::RgbaImage) -> Vec<u8> {
+    let image = image::imageops::flip_vertical(image);
+
+    let mut bytes = Vec::new();
+    for pixel in image.pixels() {
+        let [r, g, b, a] = pixel.0;
+        bytes.extend_from_slice(&[r, g, b, a.div_ceil(2)]);
+    }
+
+    bytes
 }
 
-pub fn decode_c8(bytes: &[u8], width: usize, height: usize, palette: &[u8]) -> image::RgbaImage {
-    let mut image = image::RgbaImage::new(width as _, height as _);
-
-    let mut i = 0;
-    for y in 0..height {
-        for x in 0..width {
-            let palette_index = usize::from(bytes[i]) * 4;
-            let r = palette[palette_index];
-            let g = palette[palette_index + 1];
-            let b = palette[palette_index + 2];
-            let a = palette[palette_index + 3];
-            image.put_pixel(x as u32, y as u32, image::Rgba([r, g, b, a.saturating_mul(2)]));
-            i += 1;
+pub fn encode_c4(indices: &image::GrayImage) -> Vec<u8> {
+    let indices = image::imageops::flip_vertical(indices);
+
+    let mut bytes = Vec::new();
+    for row in indices.rows() {
+        let row = row.map(|index| index.0[0]).collect::<Vec<_>>();
+        for pair in row.chunks_exact(2) {
+            bytes.push((pair[0] & 0b0000_1111) | (pair[1] << 4));
         }
     }
 
-    image::imageops::flip_vertical(&image)
+    bytes
+}
+
+pub fn encode_c8(indices: &image::GrayImage) -> Vec<u8> {
+    image::imageops::flip_vertical(indices).into_raw()
+}
+
+pub fn encode_palette(colors: &[[u8; 4]], palette_count: usize) -> Vec<u8> {
+    let mut palette = vec![0; palette_count * 4];
+    for (i, [r, g, b, a]) in colors.iter().enumerate() {
+        palette[i * 4..(i * 4) + 4].copy_from_slice(&[*r, *g, *b, a.div_ceil(2)]);
+    }
+
+    palette
+}
+
+#[cfg(test)]
+mod tests {
+    fn image(width: u32, height: u32) -> image::RgbaImage {
+        image::RgbaImage::from_fn(width, height, |x, y| {
+            image::Rgba([
+                (x * 8) as u8,
+                (y * 8) as u8,
+                ((x + y) * 8) as u8,
+                [0, 128, 254, 255][(x % 4) as usize],
+            ])
+        })
+    }
+
+    fn indices(width: u32, height: u32, count: u32) -> image::GrayImage {
+        image::GrayImage::from_fn(width, height, |x, y| image::Luma([((x + y * width) % count) as u8]))
+    }
+
+    #[test]
+    fn rows_are_stored_bottom_up() {
+        let image = image::RgbaImage::from_fn(1, 2, |_, y| image::Rgba([y as u8, 0, 0, 255]));
+        assert_eq!(super::encode_rgb8(&image), [1, 0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn rgba8_round_trip() {
+        let image = image(8, 4);
+        let bytes = super::encode_rgba8(&image);
+        assert_eq!(bytes[3], 0);
+        assert_eq!(bytes[7], 64);
+        assert_eq!(super::decode_rgba8(&bytes, 8, 4).unwrap(), image);
+    }
+
+    #[test]
+    fn rgb5_and_rgb8_round_trip() {
+        let image = image::RgbaImage::from_fn(8, 4, |x, y| image::Rgba([(x * 8) as u8, (y * 8) as u8, 248, 255]));
+        assert_eq!(super::decode_rgb5(&super::encode_rgb5(&image), 8, 4).unwrap(), image);
+        assert_eq!(super::decode_rgb8(&super::encode_rgb8(&image), 8, 4).unwrap(), image);
+    }
+
+    #[test]
+    fn indices_round_trip() {
+        let c4 = indices(8, 4, 16);
+        assert_eq!(super::decode_c4_indices(&super::encode_c4(&c4), 8, 4).unwrap(), c4);
+        let c8 = indices(16, 4, 256);
+        assert_eq!(super::decode_c8_indices(&super::encode_c8(&c8), 16, 4).unwrap(), c8);
+    }
+
+    #[test]
+    fn c4_odd_width() {
+        let mut palette = [0; 64];
+        palette[..8].copy_from_slice(&[10, 20, 30, 128, 40, 50, 60, 128]);
+        let image = super::decode_c4(&[0x10, 0x01], 3, 2, &palette).unwrap();
+        assert_eq!(image.get_pixel(0, 0).0, [40, 50, 60, 255]);
+        assert_eq!(image.get_pixel(1, 0).0, [10, 20, 30, 255]);
+        assert_eq!(image.get_pixel(0, 1).0, [10, 20, 30, 255]);
+        assert_eq!(image.get_pixel(1, 1).0, [40, 50, 60, 255]);
+        assert_eq!(image.get_pixel(2, 0).0, [0; 4]);
+        assert_eq!(image.get_pixel(2, 1).0, [0; 4]);
+    }
+
+    #[test]
+    fn palette_round_trip() {
+        let colors = [[1, 2, 3, 0], [4, 5, 6, 128], [7, 8, 9, 254]];
+        let palette = super::encode_palette(&colors, 16);
+        assert_eq!(palette.len(), 64);
+        assert_eq!(&super::decode_palette(&palette, 16).unwrap()[..3], colors);
+    }
+
+    #[test]
+    fn truncated_data() {
+        assert!(matches!(
+            super::decode_rgba8(&[0; 12], 2, 2),
+            Err(crate::Error::Truncated { .. })
+        ));
+    }
 }
@@ -1,12 +1,40 @@
-pub fn convert(bytes: &[u8], multiply_alpha: bool) -> image::RgbaImage {
-    let palette = &bytes[1..(1 + (256 * 4))];
+pub fn texture_info(bytes: &[u8], multiply_alpha: bool) -> crate::Result<crate::texture::TextureInfo> {
+    crate::bytes::slice(bytes, 0, (256 * 4) + 5)?;
 
-    let bytes = &bytes[(256 * 4) + 5..];
+    Ok(crate::texture::TextureInfo {
+        texture_type: None,
+        format: crate::texture::Format::RleC8 { multiply_alpha },
+        width: 256,
+        height: 256,
+        palette_count: 256,
+        data_offset: (256 * 4) + 5,
+        palette_offset: Some(1),
+        rle_bit_count: None,
+    })
+}
+
+pub fn decode_palette(bytes: &[u8], multiply_alpha: bool) -> crate::Result<Vec<[u8; 4]>> {
+    let palette = crate::bytes::slice(bytes, 1, 256 * 4)?;
+    Ok(palette
+        .chunks_exact(4)
+        .map(|color| {
+            let alpha = if multiply_alpha {
+                color[3].saturating_mul(2)
+            } else {
+                color[3]
+            };
+            [color[0], color[1], color[2], alpha]
+        })
+        .collect())
+}
+
+pub fn decode_indices(bytes: &[u8]) -> crate::Result<image::GrayImage> {
+    let bytes = crate::bytes::tail(bytes, (256 * 4) + 5)?;
 
     let mut decoded_bytes = std::vec::Vec::new();
 
     let mut i = 0;
-    while i < bytes.len() - 1 {
+    while i + 1 < bytes.len() {
         let count = bytes[i] as i8;
         i += 1;
 
@@ -17,23 +45,73 @@ pub fn convert(bytes: &[u8], multiply_alpha: bool) -> image::RgbaImage {
             i += 1;
         } else {
             let length = usize::from(count.unsigned_abs());
-            decoded_bytes.extend_from_slice(&bytes[i..i + length]);
+            decoded_bytes.extend_from_slice(crate::bytes::slice(bytes, i, length)?);
             i += length;
         }
     }
 
-    let mut pixels = Vec::new();
+    decoded_bytes.truncate(256 * 256);
+    if decoded_bytes.len() < 256 * 256 {
+        return Err(crate::Error::Truncated {
+            offset: 0,
+            length: 256 * 256,
+            available: decoded_bytes.len(),
+        });
+    }
+
+    let mut indices = image::GrayImage::from_raw(256, 256, decoded_bytes).unwrap();
+    image::imageops::flip_vertical_in_place(&mut indices);
+    Ok(indices)
+}
+
+pub fn convert(bytes: &[u8], multiply_alpha: bool) -> crate::Result<image::RgbaImage> {
+    let palette = decode_palette(bytes, multiply_alpha)?;
+    let indices = decode_indices(bytes)?;
+    Ok(crate::texture::expand(&indices, &palette))
+}
+
+pub fn encode(indices: &image::GrayImage) -> Vec<u8> {
+    let indices = image::imageops::flip_vertical(indices).into_raw();
+
+    let mut bytes = Vec::new();
+    let mut literal = Vec::new();
+    let mut i = 0;
+    while i < indices.len() {
+        let run_length = indices[i..].iter().take(127).take_while(|index| **index == indices[i]).count();
 
-    for palette_index in decoded_bytes {
-        pixels.push(palette[usize::from(palette_index) * 4]);
-        pixels.push(palette[(usize::from(palette_index) * 4) + 1]);
-        pixels.push(palette[(usize::from(palette_index) * 4) + 2]);
+        if run_length >= 2 {
+            if !literal.is_empty() {
+                bytes.push((literal.len() as i8).wrapping_neg() as u8);
+                bytes.append(&mut literal);
+            }
+
+            bytes.push(run_length as u8);
+            bytes.push(indices[i]);
+            i += run_length;
+        } else {
+            literal.push(indices[i]);
+            if literal.len() == 127 {
+                bytes.push((literal.len() as i8).wrapping_neg() as u8);
+                bytes.append(&mut literal);
+            }
+            i += 1;
+        }
+    }
+
+    if !literal.is_empty() {
+        bytes.push((literal.len() as i8).wrapping_neg() as u8);
+        bytes.append(&mut literal);
+    }
+
+    bytes
+}
 
-        let alpha = palette[(usize::from(palette_index) * 4) + 3];
-        let alpha = if multiply_alpha { alpha.saturating_mul(2) } else { alpha };
-        pixels.push(alpha);
+pub fn encode_palette(colors: &[[u8; 4]], multiply_alpha: bool) -> Vec<u8> {
+    let mut palette = vec![0; 256 * 4];
+    for (i, [r, g, b, a]) in colors.iter().enumerate() {
+        let a = if multiply_alpha { a.div_ceil(2) } else { *a };
+        palette[i * 4..(i * 4) + 4].copy_from_slice(&[*r, *g, *b, a]);
     }
 
-    let image = image::RgbaImage::from_raw(256, 256, pixels).unwrap();
-    image::imageops::flip_vertical(&image)
+    palette
 }
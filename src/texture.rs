@@ -0,0 +1,485 @@
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    PlayStation2Rgba8,
+    PlayStation2Rgb8,
+    PlayStation2Rgb5,
+    PlayStation2C4,
+    PlayStation2C8,
+    GameCubeCmpr,
+    GameCubeRgb5a3,
+    GameCubeRgba8,
+    GameCubeC4,
+    GameCubeC8,
+    XboxRgba8,
+    XboxRgb5,
+    XboxC8,
+    XboxBc1,
+    XboxBc2,
+    RleC8 { multiply_alpha: bool },
+}
+
+impl Format {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Format::PlayStation2Rgba8 | Format::GameCubeRgba8 | Format::XboxRgba8 => "RGBA8",
+            Format::PlayStation2Rgb8 => "RGB8",
+            Format::PlayStation2Rgb5 | Format::XboxRgb5 => "RGB5",
+            Format::GameCubeRgb5a3 => "RGB5A3",
+            Format::PlayStation2C4 | Format::GameCubeC4 => "C4",
+            Format::PlayStation2C8 | Format::GameCubeC8 | Format::XboxC8 => "C8",
+            Format::GameCubeCmpr => "CMPR",
+            Format::XboxBc1 => "BC1",
+            Format::XboxBc2 => "BC2",
+            Format::RleC8 { .. } => "RLE C8",
+        }
+    }
+
+    pub fn bit_count(&self) -> u8 {
+        match self {
+            Format::PlayStation2Rgba8 | Format::GameCubeRgba8 | Format::XboxRgba8 => 32,
+            Format::PlayStation2Rgb8 => 24,
+            Format::PlayStation2Rgb5 | Format::XboxRgb5 | Format::GameCubeRgb5a3 => 16,
+            Format::PlayStation2C8 | Format::GameCubeC8 | Format::XboxC8 | Format::XboxBc2 | Format::RleC8 { .. } => 8,
+            Format::PlayStation2C4 | Format::GameCubeC4 | Format::GameCubeCmpr | Format::XboxBc1 => 4,
+        }
+    }
+}
+
+impl Format {
+    pub fn level_length(&self, width: usize, height: usize) -> usize {
+        let gamecube_length = |format| gctex::compute_image_size(format, width as _, height as _) as usize;
+        match self {
+            Format::PlayStation2Rgba8 | Format::XboxRgba8 => width * height * 4,
+            Format::PlayStation2Rgb8 => width * height * 3,
+            Format::PlayStation2Rgb5 | Format::XboxRgb5 => width * height * 2,
+            Format::PlayStation2C4 => (width / 2) * height,
+            Format::PlayStation2C8 | Format::XboxC8 | Format::RleC8 { .. } => width * height,
+            Format::GameCubeCmpr => gamecube_length(gctex::TextureFormat::CMPR),
+            Format::GameCubeRgb5a3 => gamecube_length(gctex::TextureFormat::RGB5A3),
+            Format::GameCubeRgba8 => gamecube_length(gctex::TextureFormat::RGBA8),
+            Format::GameCubeC4 => gamecube_length(gctex::TextureFormat::I4),
+            Format::GameCubeC8 => gamecube_length(gctex::TextureFormat::I8),
+            Format::XboxBc1 => texpresso::Format::Bc1.compressed_size(width, height),
+            Format::XboxBc2 => texpresso::Format::Bc2.compressed_size(width, height),
+        }
+    }
+}
+
+impl std::fmt::Display for Format {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct TextureInfo {
+    pub texture_type: Option<u8>,
+    pub format: Format,
+    pub width: usize,
+    pub height: usize,
+    pub palette_count: usize,
+    pub data_offset: usize,
+    pub palette_offset: Option<usize>,
+    pub rle_bit_count: Option<u8>,
+}
+
+impl TextureInfo {
+    pub(crate) fn offset_by(mut self, offset: usize) -> TextureInfo {
+        self.data_offset += offset;
+        self.palette_offset = self.palette_offset.map(|palette_offset| palette_offset + offset);
+        self
+    }
+}
+
+pub(crate) fn data_length(bytes: &[u8], info: &TextureInfo) -> crate::Result<usize> {
+    let data_end = match info.palette_offset {
+        Some(palette_offset) if palette_offset > info.data_offset => palette_offset,
+        _ => bytes.len(),
+    };
+
+    data_end.checked_sub(info.data_offset).ok_or(crate::Error::Truncated {
+        offset: info.data_offset,
+        length: 0,
+        available: bytes.len(),
+    })
+}
+
+pub fn mip_levels(bytes: &[u8], info: &TextureInfo) -> crate::Result<Vec<TextureInfo>> {
+    if info.rle_bit_count.is_some() || matches!(info.format, Format::RleC8 { .. }) {
+        return Ok(vec![info.clone()]);
+    }
+
+    let data_length = data_length(bytes, info)?;
+
+    let mut levels = Vec::new();
+    let mut chain_length = 0;
+    let (mut width, mut height) = (info.width, info.height);
+    loop {
+        let level_length = info.format.level_length(width, height);
+        if chain_length + level_length > data_length || (level_length == 0 && !levels.is_empty()) {
+            break;
+        }
+
+        levels.push(TextureInfo {
+            width,
+            height,
+            data_offset: info.data_offset + chain_length,
+            ..info.clone()
+        });
+        chain_length += level_length;
+
+        if width == 1 && height == 1 {
+            break;
+        }
+        width = (width / 2).max(1);
+        height = (height / 2).max(1);
+    }
+
+    Ok(levels)
+}
+
+pub fn check_mip_chain(bytes: &[u8], info: &TextureInfo) -> crate::Result<Vec<TextureInfo>> {
+    let levels = mip_levels(bytes, info)?;
+    if info.rle_bit_count.is_some() || matches!(info.format, Format::RleC8 { .. }) {
+        return Ok(levels);
+    }
+
+    let chain_length = levels.iter().map(|level| info.format.level_length(level.width, level.height)).sum();
+    let data_length = data_length(bytes, info)?;
+    if chain_length != data_length {
+        return Err(crate::Error::MipChainMismatch {
+            level_count: levels.len(),
+            chain_length,
+            data_length,
+        });
+    }
+
+    Ok(levels)
+}
+
+pub fn decode(bytes: &[u8], info: &TextureInfo) -> crate::Result<image::RgbaImage> {
+    let (width, height) = (info.width, info.height);
+
+    let decompressed_data;
+    let data = crate::bytes::tail(bytes, info.data_offset)?;
+    let data = match info.rle_bit_count {
+        Some(bit_count) => {
+            decompressed_data = crate::the_sims_3::decode_rle(data, bit_count)?;
+            &decompressed_data
+        }
+        None => data,
+    };
+
+    let palette = match info.palette_offset {
+        Some(palette_offset) => crate::bytes::tail(bytes, palette_offset)?,
+        None => &[],
+    };
+
+    match info.format {
+        Format::PlayStation2Rgba8 => crate::playstation_2::decode_rgba8(data, width, height),
+        Format::PlayStation2Rgb8 => crate::playstation_2::decode_rgb8(data, width, height),
+        Format::PlayStation2Rgb5 => crate::playstation_2::decode_rgb5(data, width, height),
+        Format::PlayStation2C4 => crate::playstation_2::decode_c4(data, width, height, palette),
+        Format::PlayStation2C8 => crate::playstation_2::decode_c8(data, width, height, palette),
+        Format::GameCubeCmpr => crate::gamecube::decode_cmpr(data, width, height),
+        Format::GameCubeRgb5a3 => crate::gamecube::decode_rgb5a3(data, width, height),
+        Format::GameCubeRgba8 => crate::gamecube::decode_rgba8(data, width, height),
+        Format::GameCubeC4 => crate::gamecube::decode_c4(data, width, height, palette),
+        Format::GameCubeC8 => crate::gamecube::decode_c8(data, width, height, palette),
+        Format::XboxRgba8 => crate::xbox::decode_rgba8(data, width, height),
+        Format::XboxRgb5 => crate::xbox::decode_rgb5(data, width, height),
+        Format::XboxC8 => crate::xbox::decode_c8(data, width, height, palette),
+        Format::XboxBc1 => crate::xbox::decode_bc1(data, width, height),
+        Format::XboxBc2 => crate::xbox::decode_bc2(data, width, height),
+        Format::RleC8 { multiply_alpha } => crate::rle_textures::convert(bytes, multiply_alpha),
+    }
+}
+
+pub fn decode_indexed(bytes: &[u8], info: &TextureInfo) -> crate::Result<Option<(image::GrayImage, Vec<[u8; 4]>)>> {
+    let (width, height) = (info.width, info.height);
+
+    let decompressed_data;
+    let data = crate::bytes::tail(bytes, info.data_offset)?;
+    let data = match info.rle_bit_count {
+        Some(bit_count) => {
+            decompressed_data = crate::the_sims_3::decode_rle(data, bit_count)?;
+            &decompressed_data
+        }
+        None => data,
+    };
+
+    let palette = match info.palette_offset {
+        Some(palette_offset) => crate::bytes::tail(bytes, palette_offset)?,
+        None => &[],
+    };
+
+    let indexed = match info.format {
+        Format::PlayStation2C4 => (
+            crate::playstation_2::decode_c4_indices(data, width, height)?,
+            crate::playstation_2::decode_palette(palette, 16)?,
+        ),
+        Format::PlayStation2C8 => (
+            crate::playstation_2::decode_c8_indices(data, width, height)?,
+            crate::playstation_2::decode_palette(palette, 256)?,
+        ),
+        Format::GameCubeC4 => (
+            crate::gamecube::decode_c4_indices(data, width, height)?,
+            crate::gamecube::decode_palette(palette, 16)?,
+        ),
+        Format::GameCubeC8 => (
+            crate::gamecube::decode_c8_indices(data, width, height)?,
+            crate::gamecube::decode_palette(palette, 256)?,
+        ),
+        Format::XboxC8 => (
+            crate::xbox::decode_c8_indices(data, width, height)?,
+            crate::xbox::decode_palette(palette, 256)?,
+        ),
+        Format::RleC8 { multiply_alpha } => (
+            crate::rle_textures::decode_indices(bytes)?,
+            crate::rle_textures::decode_palette(bytes, multiply_alpha)?,
+        ),
+        _ => return Ok(None),
+    };
+
+    Ok(Some(indexed))
+}
+
+pub fn expand(indices: &image::GrayImage, palette: &[[u8; 4]]) -> image::RgbaImage {
+    let pixels = indices.as_raw().iter().flat_map(|index| palette[usize::from(*index)]).collect();
+    image::RgbaImage::from_raw(indices.width(), indices.height(), pixels).unwrap()
+}
+
+pub fn quantize(image: &image::RgbaImage, palette_count: usize) -> crate::Result<(image::GrayImage, Vec<[u8; 4]>)> {
+    let mut colors = Vec::new();
+    let mut color_indices = std::collections::HashMap::new();
+    for pixel in image.pixels() {
+        color_indices.entry(pixel.0).or_insert_with(|| {
+            colors.push(pixel.0);
+            colors.len() - 1
+        });
+    }
+
+    if colors.len() > palette_count {
+        return Err(crate::Error::TooManyColors {
+            color_count: colors.len(),
+            palette_count,
+        });
+    }
+
+    let indices = image::GrayImage::from_fn(image.width(), image.height(), |x, y| {
+        image::Luma([color_indices[&image.get_pixel(x, y).0] as u8])
+    });
+
+    Ok((indices, colors))
+}
+
+fn stored_data_length(bytes: &[u8], info: &TextureInfo, encoded_length: usize) -> crate::Result<usize> {
+    let data = crate::bytes::tail(bytes, info.data_offset)?;
+    match (info.format, info.rle_bit_count) {
+        (_, Some(_)) => crate::the_sims_3::rle_length(data),
+        (Format::RleC8 { .. }, None) => Ok(data.len()),
+        _ => Ok(crate::bytes::slice(data, 0, encoded_length)?.len()),
+    }
+}
+
+fn palette_count(format: Format) -> Option<usize> {
+    match format {
+        Format::PlayStation2C4 | Format::GameCubeC4 => Some(16),
+        Format::PlayStation2C8 | Format::GameCubeC8 | Format::XboxC8 | Format::RleC8 { .. } => Some(256),
+        _ => None,
+    }
+}
+
+fn encode_level(format: Format, image: &image::RgbaImage, colors: &[[u8; 4]]) -> crate::Result<Vec<u8>> {
+    let indices = || {
+        let color_indices = colors
+            .iter()
+            .enumerate()
+            .map(|(i, color)| (*color, i as u8))
+            .collect::<std::collections::HashMap<_, _>>();
+        image::GrayImage::from_fn(image.width(), image.height(), |x, y| {
+            image::Luma([color_indices[&image.get_pixel(x, y).0]])
+        })
+    };
+
+    Ok(match format {
+        Format::PlayStation2Rgba8 => crate::playstation_2::encode_rgba8(image),
+        Format::PlayStation2Rgb8 => crate::playstation_2::encode_rgb8(image),
+        Format::PlayStation2Rgb5 => crate::playstation_2::encode_rgb5(image),
+        Format::PlayStation2C4 => crate::playstation_2::encode_c4(&indices()),
+        Format::PlayStation2C8 => crate::playstation_2::encode_c8(&indices()),
+        Format::GameCubeCmpr => crate::gamecube::encode_cmpr(image),
+        Format::GameCubeRgb5a3 => crate::gamecube::encode_rgb5a3(image),
+        Format::GameCubeRgba8 => crate::gamecube::encode_rgba8(image),
+        Format::GameCubeC4 => crate::gamecube::encode_c4(&indices()),
+        Format::GameCubeC8 => crate::gamecube::encode_c8(&indices()),
+        Format::XboxRgba8 => crate::xbox::encode_rgba8(image)?,
+        Format::XboxRgb5 => crate::xbox::encode_rgb5(image)?,
+        Format::XboxC8 => crate::xbox::encode_c8(&indices())?,
+        Format::XboxBc1 => crate::xbox::encode_bc1(image),
+        Format::XboxBc2 => crate::xbox::encode_bc2(image),
+        Format::RleC8 { .. } => crate::rle_textures::encode(&indices()),
+    })
+}
+
+fn encode_palette(format: Format, colors: &[[u8; 4]]) -> Option<Vec<u8>> {
+    match format {
+        Format::PlayStation2C4 => Some(crate::playstation_2::encode_palette(colors, 16)),
+        Format::PlayStation2C8 => Some(crate::playstation_2::encode_palette(colors, 256)),
+        Format::GameCubeC4 => Some(crate::gamecube::encode_palette(colors, 16)),
+        Format::GameCubeC8 => Some(crate::gamecube::encode_palette(colors, 256)),
+        Format::XboxC8 => Some(crate::xbox::encode_palette(colors)),
+        Format::RleC8 { multiply_alpha } => Some(crate::rle_textures::encode_palette(colors, multiply_alpha)),
+        _ => None,
+    }
+}
+
+pub fn encode(bytes: &[u8], info: &TextureInfo, image: &image::RgbaImage) -> crate::Result<Vec<u8>> {
+    let (width, height) = (image.width() as usize, image.height() as usize);
+    if (width, height) != (info.width, info.height) {
+        return Err(crate::Error::DimensionMismatch {
+            expected_width: info.width,
+            expected_height: info.height,
+            width,
+            height,
+        });
+    }
+
+    let colors = match palette_count(info.format) {
+        Some(palette_count) => quantize(image, palette_count)?.1,
+        None => Vec::new(),
+    };
+
+    let data = encode_level(info.format, image, &colors)?;
+    let data = match info.rle_bit_count {
+        Some(bit_count) => crate::the_sims_3::encode_rle(&data, bit_count)?,
+        None => data,
+    };
+
+    let data_end = info.data_offset + stored_data_length(bytes, info, data.len())?;
+
+    let mut encoded_bytes = bytes[..info.data_offset].to_vec();
+    encoded_bytes.extend_from_slice(&data);
+    encoded_bytes.extend_from_slice(&bytes[data_end..]);
+
+    // A complete mip chain keeps its layout, so the smaller levels are regenerated in place. Paletted levels
+    // are scaled without blending so that they only use the colours of the top level.
+    let levels = check_mip_chain(bytes, info).unwrap_or_default();
+    let filter = match palette_count(info.format) {
+        Some(_) => image::imageops::FilterType::Nearest,
+        None => image::imageops::FilterType::Triangle,
+    };
+    for level in levels.iter().skip(1) {
+        let level_image = image::imageops::resize(image, level.width as u32, level.height as u32, filter);
+        let level_data = encode_level(info.format, &level_image, &colors)?;
+        crate::bytes::slice(&encoded_bytes, level.data_offset, level_data.len())?;
+        encoded_bytes[level.data_offset..level.data_offset + level_data.len()].copy_from_slice(&level_data);
+    }
+
+    if let (Some(palette), Some(palette_offset)) = (encode_palette(info.format, &colors), info.palette_offset) {
+        let palette_offset = if palette_offset >= data_end {
+            palette_offset - data_end + info.data_offset + data.len()
+        } else {
+            palette_offset
+        };
+        crate::bytes::slice(&encoded_bytes, palette_offset, palette.len())?;
+        encoded_bytes[palette_offset..palette_offset + palette.len()].copy_from_slice(&palette);
+    }
+
+    Ok(encoded_bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    fn info(format: super::Format, width: usize, height: usize) -> super::TextureInfo {
+        super::TextureInfo {
+            texture_type: None,
+            format,
+            width,
+            height,
+            palette_count: 0,
+            data_offset: 4,
+            palette_offset: None,
+            rle_bit_count: None,
+        }
+    }
+
+    #[test]
+    fn full_mip_chain() {
+        let bytes = vec![0; 4 + (8 * 8 + 4 * 4 + 2 * 2 + 1) * 4];
+        let levels = super::check_mip_chain(&bytes, &info(super::Format::PlayStation2Rgba8, 8, 8)).unwrap();
+        let dimensions = levels.iter().map(|level| (level.width, level.height, level.data_offset)).collect::<Vec<_>>();
+        assert_eq!(dimensions, [(8, 8, 4), (4, 4, 260), (2, 2, 324), (1, 1, 340)]);
+    }
+
+    #[test]
+    fn top_level_only() {
+        let bytes = vec![0; 4 + 8 * 8 * 4];
+        let levels = super::check_mip_chain(&bytes, &info(super::Format::PlayStation2Rgba8, 8, 8)).unwrap();
+        assert_eq!(levels.len(), 1);
+    }
+
+    #[test]
+    fn mip_chain_mismatch() {
+        let bytes = vec![0; 4 + (8 * 8 + 4 * 4 + 2 * 2 + 1) * 4 + 2];
+        assert!(matches!(
+            super::check_mip_chain(&bytes, &info(super::Format::PlayStation2Rgba8, 8, 8)),
+            Err(crate::Error::MipChainMismatch {
+                level_count: 4,
+                chain_length: 340,
+                data_length: 342
+            })
+        ));
+    }
+
+    #[test]
+    fn block_compressed_levels_round_up() {
+        let bytes = vec![0; 4 + 32 + 8 + 8 + 8];
+        let levels = super::check_mip_chain(&bytes, &info(super::Format::XboxBc1, 8, 8)).unwrap();
+        assert_eq!(levels.len(), 4);
+    }
+
+    #[test]
+    fn quantize_too_many_colors() {
+        let image = image::RgbaImage::from_fn(8, 8, |x, y| image::Rgba([(x * 8 + y) as u8, 0, 0, 255]));
+        assert!(matches!(
+            super::quantize(&image, 16),
+            Err(crate::Error::TooManyColors {
+                color_count: 64,
+                palette_count: 16
+            })
+        ));
+        let (indices, colors) = super::quantize(&image, 256).unwrap();
+        assert_eq!(super::expand(&indices, &colors), image);
+    }
+
+    #[test]
+    fn encode_round_trip() {
+        let image = image::RgbaImage::from_fn(8, 8, |x, y| image::Rgba([(x * 32) as u8, (y * 32) as u8, 0, 255]));
+        for format in [
+            super::Format::PlayStation2Rgba8,
+            super::Format::PlayStation2Rgb8,
+            super::Format::GameCubeRgba8,
+            super::Format::XboxRgba8,
+        ] {
+            let bytes = vec![0; 4 + format.level_length(8, 8)];
+            let info = info(format, 8, 8);
+            let encoded = super::encode(&bytes, &info, &image).unwrap();
+            assert_eq!(encoded.len(), bytes.len());
+            assert_eq!(super::decode(&encoded, &info).unwrap(), image, "{format}");
+        }
+    }
+
+    #[test]
+    fn encode_dimension_mismatch() {
+        let bytes = vec![0; 4 + 8 * 8 * 4];
+        assert!(matches!(
+            super::encode(
+                &bytes,
+                &info(super::Format::XboxRgba8, 8, 8),
+                &image::RgbaImage::new(4, 4)
+            ),
+            Err(crate::Error::DimensionMismatch { .. })
+        ));
+    }
+}
@@ -0,0 +1,113 @@
+use std::collections::{HashMap, HashSet};
+
+fn lines(data: &str) -> impl Iterator<Item = (usize, &str)> {
+    data.lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+}
+
+pub fn ids(data: &str) -> crate::Result<HashSet<u32>> {
+    lines(data)
+        .map(|(line_number, line)| {
+            line.parse().map_err(|_| crate::Error::InvalidTextureId {
+                line: line_number,
+                text: line.to_owned(),
+            })
+        })
+        .collect()
+}
+
+pub fn names(data: &str) -> HashSet<&str> {
+    lines(data).map(|(_, line)| line).collect()
+}
+
+#[derive(Clone, Default)]
+pub struct TextureFlags {
+    ids: HashMap<u32, bool>,
+    names: HashMap<String, bool>,
+}
+
+impl TextureFlags {
+    pub fn parse(data: &str) -> crate::Result<TextureFlags> {
+        let mut flags = TextureFlags::default();
+        for (line_number, line) in lines(data) {
+            let invalid = || crate::Error::InvalidTextureFlag {
+                line: line_number,
+                text: line.to_owned(),
+            };
+
+            let (flag, key) = line.split_once(char::is_whitespace).ok_or_else(invalid)?;
+            let specular = match flag {
+                "specular" => true,
+                "alpha" => false,
+                _ => return Err(invalid()),
+            };
+
+            let key = key.trim();
+            match key.strip_prefix("id:") {
+                Some(id) => {
+                    flags.ids.insert(id.trim().parse().map_err(|_| invalid())?, specular);
+                }
+                None => {
+                    flags.names.insert(key.to_owned(), specular);
+                }
+            }
+        }
+
+        Ok(flags)
+    }
+
+    pub fn load(path: &std::path::Path) -> crate::Result<TextureFlags> {
+        let data = std::fs::read_to_string(path).map_err(|error| crate::Error::io(path, error))?;
+        TextureFlags::parse(&data).map_err(|error| error.in_archive(path))
+    }
+
+    pub fn specular(&self, entry: &crate::arc::Entry) -> Option<bool> {
+        self.names.get(&entry.name).or_else(|| self.ids.get(&entry.id)).copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    fn entry(name: &str, id: u32) -> crate::arc::Entry<'static> {
+        crate::arc::Entry {
+            name: name.to_owned(),
+            id,
+            offset: 0,
+            bytes: &[],
+            trailer: [0; 8],
+        }
+    }
+
+    #[test]
+    fn parse_flags() {
+        let flags = super::TextureFlags::parse("# comment\n\nspecular wall a\nalpha id: 7\nalpha wall a\n").unwrap();
+        assert_eq!(flags.specular(&entry("wall a", 1)), Some(false));
+        assert_eq!(flags.specular(&entry("floor", 7)), Some(false));
+        assert_eq!(flags.specular(&entry("floor", 8)), None);
+    }
+
+    #[test]
+    fn invalid_flag_line() {
+        let result = super::TextureFlags::parse("specular wall\n# comment\nspecular id:x\n");
+        assert!(matches!(result, Err(crate::Error::InvalidTextureFlag { line: 3, .. })));
+        let result = super::TextureFlags::parse("transparent wall\n");
+        assert!(matches!(result, Err(crate::Error::InvalidTextureFlag { line: 1, .. })));
+    }
+
+    #[test]
+    fn invalid_id_line() {
+        assert_eq!(super::ids("1\n# 2\n3\n").unwrap(), [1, 3].into());
+        assert!(matches!(
+            super::ids("1\n\n12a\n"),
+            Err(crate::Error::InvalidTextureId { line: 3, ref text }) if text == "12a"
+        ));
+    }
+
+    #[test]
+    fn built_in_lists_parse() {
+        assert!(!crate::the_sims::THE_SIMS_ALPHA_TEXTURE_IDS.is_empty());
+        assert!(!crate::the_sims_bustin_out::ALPHA_TEXTURE_IDS.is_empty());
+    }
+}
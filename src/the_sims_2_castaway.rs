@@ -1,640 +1,4 @@
-pub const SPECULAR_FILE_NAMES: [&str; 638] = [
-    "_garbage",
-    "accessorycanofpetfood",
-    "accessorycleaningspounge",
-    "accessorycoffeecup",
-    "accessorylitterboxbag",
-    "accessorypetfoodbox",
-    "accessorypetfoodboxmammal",
-    "accessoryringboxfabric",
-    "accessoryshovelsurface",
-    "af_bc_watch_ovaldiamond_texture1",
-    "af_hh_dummy",
-    "af_ht_divinghelmet_norm_norm_1",
-    "af_ht_gumby_norm_norm_1",
-    "af_ht_llamacrown_norm_norm_1",
-    "af_ht_pirate_norm_norm_1",
-    "aliencarving",
-    "am_ht_bananaleaf_norm_norm_1",
-    "am_ht_divinghelmet_norm_norm_1",
-    "am_ht_gumby_norm_norm_1",
-    "am_ht_llamacrown_norm_norm_1",
-    "am_ht_pirate_norm_norm_1",
-    "ancientstaircase",
-    "appliance_bar",
-    "appliance_food_processor_groove",
-    "appliance_stove_alien",
-    "appliance_stove_bbq_charcoal",
-    "appliance_stove_modern_body",
-    "appliance_stove_retro",
-    "appliance_stove_x_stainless",
-    "art_sculpture_x_parrot",
-    "artChimpMask",
-    "artsculptureeasterislandhead",
-    "ArtSculptureFlamingo",
-    "artSculptureGardener",
-    "artSculptureMaid",
-    "artSculptureMailbox",
-    "bamboo mat",
-    "bamboo_survival",
-    "bamboo_wall",
-    "BambooDurableBlack",
-    "BambooGreen",
-    "bambootile",
-    "bananaInsideColor",
-    "BananaLeaf",
-    "bananaleaf_tileable",
-    "bananaLeafLargeTile",
-    "bananaleaftexture",
-    "bananatreebark",
-    "bathtub_oriental_brown",
-    "beachcliffgrey",
-    "beachcliffgreya",
-    "beachterrain",
-    "bigrockbeach",
-    "billboard_verizon02_white",
-    "billboard_verizon02_white_02",
-    "biodome_submarine",
-    "black",
-    "boar_default",
-    "bridge",
-    "cabinet_armoire_punk_base",
-    "cabinet_armoire_punk_door",
-    "calib_bright_ps2_text",
-    "Candle_wax",
-    "carry_bills_orange",
-    "carry_bills_red",
-    "carry_bills_yellow",
-    "carry_newspaper",
-    "carry_newspaper_old",
-    "carry_snack_chips",
-    "carry_soup_in_pan",
-    "carry_tray_boxes_cartons_etc",
-    "cas_dockplanks",
-    "cas_lightning_grad",
-    "cas_mirror_stainless",
-    "cas_pictureframe_frames",
-    "cas_sailboat",
-    "cas_tiki_totem",
-    "cas_wood_post",
-    "cave",
-    "cavedoorcarvings",
-    "caveflatrock",
-    "cavepaintings",
-    "cavepaintings2",
-    "cavern",
-    "cavestalag1",
-    "cavestalag3",
-    "cavestonedoor",
-    "ceiling",
-    "chicken_default",
-    "chimp_black",
-    "chimp_default",
-    "chimp_grey",
-    "citrustexture",
-    "clamshellpink",
-    "cliffgreyrock",
-    "clifflush",
-    "clifflush4",
-    "cliffmossgrey",
-    "cliffrockmoss",
-    "CoconutTexture",
-    "cocounut_huskM",
-    "colorcycles",
-    "condo_innerlot_stucco",
-    "condo_innerlot_woodsiding",
-    "containedpetgoldfish_brush",
-    "containedpetgoldfish_food",
-    "containedpetgoldfish_gravel",
-    "coralrock",
-    "coralrock2",
-    "count_blanc_bathroom_counter_face",
-    "count_blanc_bathroom_counter_side",
-    "count_blanc_bathroom_counter_top",
-    "counter_alien_straight",
-    "counter_butcherblock",
-    "counter_c_kitchen_ikea",
-    "counter_cheap_sides",
-    "counter_commercial_side",
-    "counter_commercial_top",
-    "counter_formica_gray",
-    "counter_groovy",
-    "counter_industrial",
-    "counter_mod_salmon",
-    "counter_outdoor_straight",
-    "counter_retro_bottom",
-    "counter_sides_retro",
-    "counter_slat_board",
-    "counter_slat_board_top",
-    "counter_tiled",
-    "counter_tiled_sides",
-    "counter_toolchest",
-    "counter_western",
-    "counter_western_top",
-    "counter_wiseguys",
-    "counter_x_butcherblock",
-    "counterAntiqueClean",
-    "counterMissionBase",
-    "counterMissionClean",
-    "counterMissionGlass",
-    "counterPetCrazyClean",
-    "cozmo_wood_03",
-    "datapad",
-    "datepalmroof",
-    "decorative_gnome_cheat",
-    "dirt_01",
-    "door_western_plain",
-    "door_western_plain_wood",
-    "driftwood",
-    "drinkUmbrella",
-    "DurableBamboo",
-    "durablebamboobricks",
-    "DurableClothBrown",
-    "DurableClothGreen",
-    "DurableClothWhite",
-    "DurablePanel",
-    "DurableShower",
-    "DurableStone",
-    "electronics_alarm_burglar_02",
-    "electronics_alarm_burgler",
-    "electronics_alarm_clock",
-    "electronics_alarm_fire_main",
-    "electronics_console",
-    "electronics_phone_wall_standard",
-    "electronics_phone_wall_standard_screen",
-    "electronics_vending_drink_redbullcan",
-    "electronics_vending_drink_redbullcan_02",
-    "electronics_vending_drink_redbullcan_cas",
-    "electronics_vending_drink_redbullcan_cas_02",
-    "electronics_vending_redbull_panels",
-    "electronics_vending_redbull_panels_02",
-    "electronics_vending_redbull_stand",
-    "electronics_vending_redbull_stand_02",
-    "endgame_scene2",
-    "endgame_scene3_bg",
-    "endgame_scene4_bg",
-    "endgame_scene5_bg",
-    "endgame_scene6_bg",
-    "exterior01",
-    "exterior03",
-    "exterior04",
-    "exteriorfloor",
-    "fence_japanese",
-    "fence_white_picket",
-    "fenceExpensive",
-    "firecoalsoverlay",
-    "firepit_durable_stone",
-    "fireWood",
-    "FishMarlin",
-    "floor_derelict_02",
-    "floor_dirt_yellow",
-    "floor_editoggler",
-    "floor_tile_invisible",
-    "floortile_apt3_linoleum_punk",
-    "flowertulips",
-    "food_object_bread",
-    "food_object_burrito",
-    "food_object_casserole",
-    "food_object_pizza",
-    "food_object_roast",
-    "food_object_rotten",
-    "food_object_salad",
-    "food_object_sandwich",
-    "forest_mountain_catbuttes_cats",
-    "forest_rock",
-    "fruittexture",
-    "FurnitureMakeshift",
-    "FurnitureMakeshiftTile",
-    "g_bladder_icon",
-    "g_bladderjob_icon",
-    "game_checker_board",
-    "game_drivingrange",
-    "gardengnome",
-    "generictreebark",
-    "global_appliance_retro",
-    "global_bamboo_dead",
-    "global_bamboo_top",
-    "global_brass",
-    "global_copper",
-    "global_decorative_japanese02",
-    "global_formica_white",
-    "global_foundry_welded_metal",
-    "global_gold",
-    "global_gold_reflective",
-    "global_metal_diamondgraphite",
-    "global_metal_shiny",
-    "global_metal_stainless_01",
-    "global_molding_wood_white",
-    "global_plastic_black",
-    "global_plastic_cream",
-    "global_velvet_wiseguys",
-    "global_vinyl_rib",
-    "global_wood_brown",
-    "global_wood_red",
-    "gravel_64",
-    "heiroglyphicStele",
-    "hill_terrain",
-    "hitchingpost",
-    "hitchingpostaged",
-    "hitchingpostrecent",
-    "hitchingpoststone",
-    "house_load0",
-    "house_load1",
-    "house_load2",
-    "house_load3",
-    "house_load4",
-    "house_load5",
-    "house_load6",
-    "house_load7",
-    "icon_cash",
-    "interior03",
-    "interior04",
-    "is3cliff2",
-    "is3clifflush",
-    "is3clifflush4",
-    "is3cliffmoss2",
-    "is3cliffmoss4",
-    "is3cliffvolcanic",
-    "is3cliffvolcanicmoss",
-    "is3pitpattern",
-    "is3stonesamphi",
-    "isairplaneradiocolor",
-    "isbanyantreerootscolor",
-    "isbanyantreetrunkcolor",
-    "isbarnaclerock",
-    "isbobbercolor",
-    "ischeatgnomeaccessoriescolor",
-    "iscoconutbowlcolor",
-    "iscornstalkcolor",
-    "iscratecolor",
-    "iscuttingboard",
-    "isDatePalmTile",
-    "isDateTexture",
-    "isdrumcolor",
-    "isflattreebarkcolor",
-    "isforgecolor",
-    "isforgepatternscolor",
-    "isgroundgreen",
-    "isHamResource",
-    "island01_distant",
-    "ismonkeyhorncolor",
-    "isplateempty",
-    "isplatefull",
-    "isplatehalfempty",
-    "isPocketknifeTexture",
-    "isreedmatpattern",
-    "isropestone",
-    "isrustcolor",
-    "isscarecrowheadcolor",
-    "isshellclamcolor",
-    "isshellcolor",
-    "isshellconch",
-    "isSleepingMatRolls",
-    "issoapplantleavescolor",
-    "issoilpatchcolor",
-    "istikitorchface",
-    "istrashbag",
-    "istwistedwoodtexture",
-    "iswoodenworkbenchdesign",
-    "isyellowbamboocolor",
-    "jungletree",
-    "k_bodnhead_cbeige",
-    "k_bodnhead_cblack",
-    "k_bodnhead_cblue",
-    "k_bodnhead_cdarkbrown",
-    "k_bodnhead_cgolden",
-    "k_bodnhead_cgreen",
-    "k_bodnhead_clightbrown",
-    "k_bodnhead_corange",
-    "k_bodnhead_cpink",
-    "k_bodnhead_cpurple",
-    "k_bodnhead_cred",
-    "k_bodnhead_csilver",
-    "k_bodnhead_cwhite",
-    "k_bodnhead_cyellow",
-    "kicktail_metal_02",
-    "labrador_fur",
-    "LampFloorDurableShadeOff",
-    "LampFloorDurableShadeOn",
-    "LampFloorStylishShadeOff",
-    "LampFloorStylishShadeOn",
-    "LampTableDurableShadeOff",
-    "LampTableStylishShadeOn",
-    "largerockwall_base_waterfall",
-    "largerockwall_grey",
-    "LlamaCarving",
-    "LlamaCrown",
-    "loading_subway_map",
-    "mailbox_colonial",
-    "mangotreebark",
-    "metal_panel02",
-    "metal_rusty_gradiant",
-    "metallron",
-    "microwave_expensive",
-    "misc_movieprops",
-    "mountain",
-    "mountain_top",
-    "mountainlava",
-    "mountains",
-    "NautilusShell",
-    "neon_blue",
-    "neon_green",
-    "neon_white",
-    "neon_yellow",
-    "new_cursor_03",
-    "new_cursor_03_buildmode",
-    "new_cursor_buymode",
-    "new_sand",
-    "newPalmBark",
-    "nothing",
-    "numica_counter_face",
-    "numica_counter_side",
-    "numica_counter_top",
-    "OuthouseStylishRoof",
-    "PalapasAlpha",
-    "PalapasTile",
-    "palmtreetexture",
-    "pet_bone_dog",
-    "pet_bone_nylabone",
-    "pet_bone_rope",
-    "pet_toy_chew_squeaky_hotdog",
-    "pet_toy_chewtoy_pbob",
-    "pet_toy_chewtoy_squeaky_newspaper",
-    "pet_toy_chewtoy_squeaky_shoe",
-    "pet_toy_tragicclown",
-    "pet_toychewblue",
-    "pineappleTexture",
-    "placeholder",
-    "plant_floor_planter_romantic",
-    "plant_floor_rubber_tree",
-    "plant_tree_saguro",
-    "plantFloorOrchidDead",
-    "plantFloorOrchidHealthy",
-    "plantFloorOrchidPot",
-    "plaza_atm_main",
-    "plaza_phonebooth",
-    "plumbing_bathtub_hitech_jets",
-    "plumbing_sink_counter_retro",
-    "plumbing_sink_floor_porcelain",
-    "plumbing_toilet_retro",
-    "potClayGlazed",
-    "PotClayPainted",
-    "prop_banana",
-    "prop_bills",
-    "prop_book",
-    "prop_colacan",
-    "prop_dustpan",
-    "prop_dustpanash",
-    "prop_electronics_vending_hotdog_object",
-    "prop_energycan",
-    "prop_extinguisher",
-    "prop_game_drivingrange_ball_propa",
-    "prop_handbroom",
-    "prop_handheld_game",
-    "prop_hobostick",
-    "prop_knife",
-    "prop_magazine",
-    "prop_money",
-    "prop_mop",
-    "prop_newspaper",
-    "prop_paintbrush_art",
-    "prop_redbullcan",
-    "prop_redbullcan_02",
-    "prop_remote",
-    "prop_screwdriver",
-    "prop_scrubbrush",
-    "prop_sofa_monkey_paw",
-    "prop_spoon",
-    "prop_violin",
-    "prop_wateringcan",
-    "prop_wine_bottle",
-    "prop_wrench",
-    "propClam",
-    "propgreenbamboobundle",
-    "propsimplespeartexture",
-    "PropToolsword",
-    "pumice",
-    "puppykittenballoon_balloon",
-    "puppykittenballoon_box",
-    "puppykittenbasket_basket",
-    "redbull_small",
-    "redbull_small_02",
-    "reeds_wall",
-    "reeds_walla",
-    "rep_01",
-    "rep_02",
-    "rep_03",
-    "rep_04",
-    "rep_05",
-    "rivercliff",
-    "rockcliff1",
-    "rockcliff_lot11",
-    "rockflatgrey",
-    "rockflatred",
-    "rockflint",
-    "rockmoss",
-    "rockplants",
-    "rocks_lake_shore",
-    "rocktextureTranslot",
-    "ropecoil",
-    "rug_expensive",
-    "sadClownPainting",
-    "sandbuddycolor",
-    "scenery_neon_walkway",
-    "scenery_tree_shrub_bark",
-    "secretrock",
-    "ship_board",
-    "ship_board2",
-    "shower_towel",
-    "sink_bowl_copper",
-    "skill_creative_painting1",
-    "skill_creative_painting2",
-    "skill_creative_painting3",
-    "skill_creative_painting_canvas",
-    "skill_mental_head",
-    "skullrock",
-    "skullrock1",
-    "skydome_gradient",
-    "smalltwigstexture",
-    "snpc_paperboy",
-    "spikyplanttexture",
-    "staircasedoor",
-    "staircasedoordeco",
-    "stalactite",
-    "statuetest01",
-    "statuetext",
-    "stoneSurvival",
-    "StoveStylish",
-    "StylishBrick",
-    "StylishCarving",
-    "StylishClothBlue",
-    "StylishClothWhite",
-    "StylishGlass",
-    "StylishPanel",
-    "stylishteak",
-    "StylishTikiCarving",
-    "stylishwood",
-    "subway_map_brick",
-    "SurvivalBamboo",
-    "SurvivalBambooTile",
-    "SurvivalBinding",
-    "table_japanese_flower",
-    "tableDeskAntique",
-    "templateareastoavoid",
-    "templebigstones",
-    "templesmallstones",
-    "templestonesall",
-    "terrain_ground_jap_noise",
-    "tiled_counter_face",
-    "tiled_counter_side",
-    "tiled_counter_top",
-    "TileStylishRed",
-    "TileStylishRedAlpha",
-    "toilet_bronze",
-    "tombstone",
-    "transition_cas_wardrobe",
-    "transition_cas_wardrobe_02",
-    "transition_cas_wardrobe_03",
-    "transitionCASdresserPetCrazy",
-    "trashcan_bottomless",
-    "treasureMap",
-    "tree_alien",
-    "tree_birch_bark",
-    "tree_dirt",
-    "tree_maple_bark",
-    "tree_rose_bark",
-    "tree_willow_bark",
-    "treeNub",
-    "tutorial_rep_icon",
-    "twitchomatic_skill_zonebar",
-    "ui_creditscreen",
-    "vehicle_verizonvan",
-    "vehicle_verizonvan_02",
-    "verizon_logolong",
-    "villagelava",
-    "volcanocliff",
-    "w_bodnhead_cblack",
-    "w_bodnhead_cblue",
-    "w_bodnhead_cbluegrey",
-    "w_bodnhead_ccream",
-    "w_bodnhead_cdkbrown",
-    "w_bodnhead_cdkgrey",
-    "w_bodnhead_cgolden",
-    "w_bodnhead_cgreen",
-    "w_bodnhead_cmedbrown",
-    "w_bodnhead_cpink",
-    "w_bodnhead_cpurple",
-    "w_bodnhead_cred",
-    "w_bodnhead_credkisspoint",
-    "w_bodnhead_csilver",
-    "w_bodnhead_cwhite",
-    "wall_atomicrustic_left",
-    "wall_atomicrustic_right",
-    "wall_brick_qb1_grey",
-    "wall_brick_vertical_concrete_base",
-    "wall_curtain_red",
-    "wall_exterior_redwoodshingle",
-    "wall_floral_euroblue",
-    "wall_flw_left",
-    "wall_flw_middle",
-    "wall_flw_middleup",
-    "wall_flw_right",
-    "wall_flw_stainedglass",
-    "wall_mission_blackwhite",
-    "wall_paintburgundy_k2crech2_md",
-    "wall_paintburntorange_k2crech2_lt",
-    "wall_paintjewelspotlightgreen",
-    "wall_panel_angledclap_lightwood",
-    "wall_panel_horizontalwood_teak",
-    "wall_panel_woodmissionleft_brown",
-    "wall_panel_woodmissionleftcenter_brown",
-    "wall_panel_woodmissionrightcenter_brown",
-    "wall_panelwood_birch",
-    "wall_panelwood_inlaytraditional2",
-    "wall_paper_bakeryblue_stripesolid",
-    "wall_paper_bearhunter_stripesolid",
-    "wall_paper_brownleaf",
-    "wall_paper_storyborderfloral_6",
-    "wall_paperdamask_wdouble_md",
-    "wall_pettheme_bonebrick",
-    "wall_pettheme_catnosecurtain",
-    "wall_pettheme_catnosecurtain_2",
-    "wall_pettheme_paintdogbones",
-    "wall_petthemed_paintpawflower_brown",
-    "wall_petthemed_paintpawflower_white",
-    "wall_pouredstucco_tan",
-    "wall_stone_brown",
-    "wall_stone_grey",
-    "wall_stone_greystonefence",
-    "wall_stone_stackedstone",
-    "wall_wallpapermedford",
-    "wallpaper_derelict_02",
-    "wallpaper_plain_blue",
-    "wallpaper_plain_white",
-    "walls_allien_03",
-    "walls_allien_steelout",
-    "walls_allien_trailer",
-    "walls_allien_trailerbedroom",
-    "walls_cliff_01",
-    "walls_cliff_02",
-    "walls_funky_02",
-    "walls_groovy_pat03",
-    "walls_house_02",
-    "walls_house_06",
-    "walls_house_09",
-    "walls_industrial_concrete",
-    "walls_industrial_concretetile_blue",
-    "walls_industrial_grayconcrete",
-    "walls_jap_02",
-    "walls_jap_bath",
-    "walls_jap_out",
-    "walls_jap_paper",
-    "walls_mesa_03",
-    "walls_mesa_gallery",
-    "walls_mesa_out_02",
-    "walls_paint_woodgreen",
-    "walls_residental_brick_red",
-    "walls_residental_metal_white",
-    "walls_residental_paint_cream",
-    "walls_residental_paint_offwhite",
-    "walls_residental_paper_blue",
-    "walls_residental_tile_blue",
-    "walls_residental_wood_panel",
-    "walls_residental_wood_red",
-    "walls_residental_wood_stripe",
-    "walls_residental_wood_white",
-    "walls_residential_drkbrwnbase",
-    "walls_residential_greydiamondbath",
-    "walls_residential_lightgreywainscot",
-    "walls_residential_tilehalfdarkblue",
-    "walls_residential_whiteclapboard",
-    "walls_residential_woodpanel01",
-    "walls_residential_woodshingle",
-    "walls_stone_blueorange",
-    "walls_stone_white",
-    "walls_west_bath",
-    "walls_west_bed",
-    "walls_west_hotel",
-    "walls_west_out01",
-    "walls_west_out02",
-    "walls_west_rest",
-    "watertempledeco",
-    "watertemplewalls",
-    "wheel_truck",
-    "white",
-    "windsor_door",
-    "wood01",
-    "wood02",
-    "wood_catamaran_a",
-    "wood_catamaran_b",
-    "wood_crate",
-    "wood_crate_dirty",
-    "wood_post",
-    "woods_trees_a",
-    "woods_trees_c",
-    "woods_trees_g",
-    "woodsbasicbrown",
-    "woodtine",
-    "wrappers",
-    "yuccaflowertexture",
-];
+pub static SPECULAR_FILE_NAMES: std::sync::LazyLock<std::collections::HashSet<&'static str>> =
+    std::sync::LazyLock::new(|| {
+        crate::texture_flags::names(include_str!("../data/the_sims_2_castaway_specular_file_names.txt"))
+    });
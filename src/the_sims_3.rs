@@ -1,131 +1,137 @@
-fn decode_rle(bytes: &[u8], bit_count: u8) -> Vec<u8> {
-    let rle_bytes_length = u32::from_le_bytes(bytes[0..4].try_into().unwrap());
-    let rle_bytes = &bytes[4..4 + rle_bytes_length as usize];
+use crate::texture::{Format, TextureInfo};
+
+pub fn decode_rle(bytes: &[u8], bit_count: u8) -> crate::Result<Vec<u8>> {
+    if !matches!(bit_count, 8 | 16 | 32) {
+        return Err(crate::Error::UnsupportedBitCount { bit_count });
+    }
+
+    let rle_bytes_length = crate::bytes::u32(bytes, 0, crate::Endianness::Little)?;
+    let rle_bytes = crate::bytes::slice(bytes, 4, rle_bytes_length as usize)?;
 
     let mut decompressed_data = Vec::new();
 
+    let pixel_length = usize::from(bit_count) / 8;
+
     let mut i = 0;
-    while i < rle_bytes.len() - 1 {
+    while i + 1 < rle_bytes.len() {
         let count = rle_bytes[i];
 
-        for _ in 0..i32::from(count) {
-            match bit_count {
-                8 => {
-                    decompressed_data.push(rle_bytes[i + 1]);
-                }
-                16 => {
-                    decompressed_data.push(rle_bytes[i + 1]);
-                    decompressed_data.push(rle_bytes[i + 2]);
-                }
-                32 => {
-                    decompressed_data.push(rle_bytes[i + 1]);
-                    decompressed_data.push(rle_bytes[i + 2]);
-                    decompressed_data.push(rle_bytes[i + 3]);
-                    decompressed_data.push(rle_bytes[i + 4]);
-                }
-                _ => panic!(),
+        if count > 0 {
+            let pixel = crate::bytes::slice(rle_bytes, i + 1, pixel_length)?;
+            for _ in 0..count {
+                decompressed_data.extend_from_slice(pixel);
             }
         }
 
-        i += 1 + usize::from(bit_count) / 8;
+        i += 1 + pixel_length;
     }
 
-    decompressed_data
+    Ok(decompressed_data)
 }
 
-fn convert(bytes: &[u8]) -> image::RgbaImage {
-    let bytes = &bytes[16..];
-    let null_position = bytes.iter().position(|x| *x == 0).unwrap();
+pub fn encode_rle(bytes: &[u8], bit_count: u8) -> crate::Result<Vec<u8>> {
+    if !matches!(bit_count, 8 | 16 | 32) {
+        return Err(crate::Error::UnsupportedBitCount { bit_count });
+    }
 
-    let width = usize::from(u16::from_be_bytes(
-        bytes[null_position + 21..null_position + 23].try_into().unwrap(),
-    ));
-    let height = usize::from(u16::from_be_bytes(
-        bytes[null_position + 23..null_position + 25].try_into().unwrap(),
-    ));
+    let pixel_length = usize::from(bit_count) / 8;
 
-    let texture_type = bytes[null_position + 29];
+    let mut rle_bytes = Vec::new();
+    let mut pixels = bytes.chunks_exact(pixel_length).peekable();
+    while let Some(pixel) = pixels.next() {
+        let mut count = 1u8;
+        while count < u8::MAX && pixels.peek() == Some(&pixel) {
+            pixels.next();
+            count += 1;
+        }
 
-    let texture_bit_count = bytes[null_position + 31];
+        rle_bytes.push(count);
+        rle_bytes.extend_from_slice(pixel);
+    }
 
-    let palette_count = usize::from(u16::from_be_bytes(
-        bytes[null_position + 25..null_position + 27].try_into().unwrap(),
-    ));
-    let palette_bit_count = usize::from(bytes[null_position + 32]);
+    let rle_bytes_length = u32::try_from(rle_bytes.len()).map_err(|_| crate::Error::ArchiveTooLarge {
+        length: rle_bytes.len(),
+    })?;
 
-    let palette_length = (palette_count * palette_bit_count) / 8;
+    let mut compressed_data = rle_bytes_length.to_le_bytes().to_vec();
+    compressed_data.extend_from_slice(&rle_bytes);
+    Ok(compressed_data)
+}
 
-    match texture_type {
-        0x1 => {
-            let bytes = &bytes[null_position + 37..];
+pub(crate) fn rle_length(bytes: &[u8]) -> crate::Result<usize> {
+    Ok(4 + crate::bytes::u32(bytes, 0, crate::Endianness::Little)? as usize)
+}
 
-            let mut pixels = Vec::new();
-            for i in 0..width * height {
-                pixels.push(bytes[i * 3]);
-                pixels.push(bytes[(i * 3) + 1]);
-                pixels.push(bytes[(i * 3) + 2]);
-                pixels.push(255);
-            }
+pub fn texture_info(bytes: &[u8]) -> crate::Result<TextureInfo> {
+    let null_position = 16 + crate::bytes::null_position(bytes, 16)?;
 
-            let image = image::RgbaImage::from_raw(width as _, height as _, pixels).unwrap();
-            image::imageops::flip_vertical(&image)
-        }
-        0x81 => crate::gamecube::decode_cmpr(&bytes[null_position + 37..], width, height),
+    let header = &bytes[null_position..];
+
+    let width = usize::from(crate::bytes::u16(header, 21, crate::Endianness::Big)?);
+    let height = usize::from(crate::bytes::u16(header, 23, crate::Endianness::Big)?);
+
+    let texture_type = crate::bytes::u8(header, 29)?;
+
+    let texture_bit_count = crate::bytes::u8(header, 31)?;
+
+    let palette_count = usize::from(crate::bytes::u16(header, 25, crate::Endianness::Big)?);
+    let palette_bit_count = usize::from(crate::bytes::u8(header, 32)?);
+
+    let palette_length = (palette_count * palette_bit_count) / 8;
+
+    let mut palette_offset = None;
+    let mut rle_bit_count = None;
+
+    let format = match texture_type {
+        0x1 => Format::PlayStation2Rgb8,
+        0x81 => Format::GameCubeCmpr,
         0x82 => {
-            let decompressed_data = decode_rle(&bytes[null_position + 37..], texture_bit_count);
-            crate::gamecube::decode_rgb5a3(&decompressed_data, width, height)
+            rle_bit_count = Some(texture_bit_count);
+            Format::GameCubeRgb5a3
         }
         0x85 => {
-            let decompressed_data = decode_rle(&bytes[null_position + 37..], texture_bit_count);
-            crate::gamecube::decode_rgba8(&decompressed_data, width, height)
+            rle_bit_count = Some(texture_bit_count);
+            Format::GameCubeRgba8
         }
         0x89 => {
-            assert!(palette_bit_count == 32 && palette_count == 16);
-
-            let palette = &bytes[bytes.len() - palette_length..];
+            if palette_bit_count != 32 || palette_count != 16 {
+                return Err(crate::Error::UnsupportedPaletteCount { palette_count });
+            }
 
-            crate::gamecube::decode_c4(&bytes[null_position + 37..], width, height, palette)
+            palette_offset = Some(crate::bytes::last_offset(bytes, palette_length)?);
+            Format::GameCubeC4
         }
         0x8a => {
-            assert!(palette_bit_count == 32 && palette_count == 256);
-
-            let decompressed_data = decode_rle(&bytes[null_position + 37..], texture_bit_count);
+            if palette_bit_count != 32 || palette_count != 256 {
+                return Err(crate::Error::UnsupportedPaletteCount { palette_count });
+            }
 
-            let palette = &bytes[bytes.len() - palette_length..];
+            rle_bit_count = Some(texture_bit_count);
+            palette_offset = Some(crate::bytes::last_offset(bytes, palette_length)?);
+            Format::GameCubeC8
+        }
+        _ => return Err(crate::Error::UnknownTextureType { texture_type }),
+    };
 
-            crate::gamecube::decode_c8(&decompressed_data, width, height, palette)
+    if let Some(bit_count) = rle_bit_count {
+        if !matches!(bit_count, 8 | 16 | 32) {
+            return Err(crate::Error::UnsupportedBitCount { bit_count });
         }
-        _ => panic!(),
     }
-}
-
-pub fn extract_textures(textures_path: &std::path::Path, output_path: &std::path::Path) {
-    std::fs::create_dir_all(output_path).unwrap();
 
-    let textures = std::fs::read(textures_path).unwrap();
-
-    let file_list = crate::arc::list_files(&textures, crate::Endianness::Big);
-
-    for (name, _, bytes) in file_list {
-        let image = convert(bytes);
-        crate::save_texture(image, &name, output_path, SPECULAR_FILE_NAMES.contains(&name.as_str()));
-    }
+    Ok(TextureInfo {
+        texture_type: Some(texture_type),
+        format,
+        width,
+        height,
+        palette_count: if palette_offset.is_some() { palette_count } else { 0 },
+        data_offset: null_position + 37,
+        palette_offset,
+        rle_bit_count,
+    })
 }
 
-const SPECULAR_FILE_NAMES: [&str; 15] = [
-    "appliance_bar",
-    "count_blanc_bathroom_counter_top",
-    "counter_butcherblock",
-    "counter_commercial_top",
-    "counter_industrial",
-    "counter_slat_board",
-    "counter_tiled",
-    "electronics_tv_plasma_1x3",
-    "GL_Doors04",
-    "GL_DoorsHandle01",
-    "GL_DoorsHandle02",
-    "seating_sofa_modern_green",
-    "tiled_counter_top",
-    "treepear_bark",
-    "windsor_door",
-];
+pub static SPECULAR_FILE_NAMES: std::sync::LazyLock<std::collections::HashSet<&'static str>> =
+    std::sync::LazyLock::new(|| {
+        crate::texture_flags::names(include_str!("../data/the_sims_3_specular_file_names.txt"))
+    });
@@ -1,2250 +1,135 @@
-fn convert_playstation_2_texture(bytes: &[u8]) -> image::RgbaImage {
-    let bytes = &bytes[12..];
-    let null_position = bytes.iter().position(|x| *x == 0).unwrap();
+use crate::texture::{Format, TextureInfo};
 
-    let bytes = &bytes[null_position..];
+pub fn playstation_2_texture_info(bytes: &[u8]) -> crate::Result<TextureInfo> {
+    let null_position = 12 + crate::bytes::null_position(bytes, 12)?;
 
-    let width = usize::from(u16::from_le_bytes(bytes[17..19].try_into().unwrap()));
-    let height = usize::from(u16::from_le_bytes(bytes[19..21].try_into().unwrap()));
+    let header = &bytes[null_position..];
 
-    let image_bytes = &bytes[33..];
+    let width = usize::from(crate::bytes::u16(header, 17, crate::Endianness::Little)?);
+    let height = usize::from(crate::bytes::u16(header, 19, crate::Endianness::Little)?);
 
-    let texture_type = bytes[25];
-    match texture_type {
+    let texture_type = crate::bytes::u8(header, 25)?;
+    let mut palette_count = 0;
+    let mut palette_offset = None;
+
+    let format = match texture_type {
         0 => {
-            let palette_count = usize::from(u16::from_le_bytes(bytes[21..23].try_into().unwrap()));
-            match palette_count {
-                16 => {
-                    let palette = &bytes[bytes.len() - 64..];
-                    crate::playstation_2::decode_c4(image_bytes, width, height, palette)
-                }
-                256 => {
-                    let palette = &bytes[bytes.len() - 1024..];
-                    crate::playstation_2::decode_c8(image_bytes, width, height, palette)
-                }
-                _ => panic!(),
-            }
+            palette_count = usize::from(crate::bytes::u16(header, 21, crate::Endianness::Little)?);
+            let format = match palette_count {
+                16 => Format::PlayStation2C4,
+                256 => Format::PlayStation2C8,
+                _ => return Err(crate::Error::UnsupportedPaletteCount { palette_count }),
+            };
+            palette_offset = Some(crate::bytes::last_offset(bytes, palette_count * 4)?);
+            format
         }
         1 => {
-            let bit_count = bytes[27];
+            let bit_count = crate::bytes::u8(header, 27)?;
             match bit_count {
-                16 => crate::playstation_2::decode_rgb5(image_bytes, width, height),
-                32 => crate::playstation_2::decode_rgba8(image_bytes, width, height),
-                _ => panic!(),
+                16 => Format::PlayStation2Rgb5,
+                32 => Format::PlayStation2Rgba8,
+                _ => return Err(crate::Error::UnsupportedBitCount { bit_count }),
             }
         }
-        _ => panic!(),
-    }
+        _ => return Err(crate::Error::UnknownTextureType { texture_type }),
+    };
+
+    Ok(TextureInfo {
+        texture_type: Some(texture_type),
+        format,
+        width,
+        height,
+        palette_count,
+        data_offset: null_position + 33,
+        palette_offset,
+        rle_bit_count: None,
+    })
 }
 
-pub fn extract_playstation_2_textures(textures_path: &std::path::Path, output_path: &std::path::Path) {
-    std::fs::create_dir_all(output_path).unwrap();
-
-    let textures = std::fs::read(textures_path).unwrap();
+pub fn gamecube_texture_info(bytes: &[u8]) -> crate::Result<TextureInfo> {
+    let null_position = 12 + crate::bytes::null_position(bytes, 12)?;
 
-    let file_list = crate::arc::list_files(&textures, crate::Endianness::Little);
-
-    for (name, _, bytes) in file_list {
-        let image = convert_playstation_2_texture(bytes);
-        crate::save_texture(image, &name, output_path, SPECULAR_FILE_NAMES.contains(&name.as_str()));
-    }
-}
+    let header = &bytes[null_position..];
 
-fn convert_gamecube_texture(bytes: &[u8]) -> image::RgbaImage {
-    let bytes = &bytes[12..];
-    let null_position = bytes.iter().position(|x| *x == 0).unwrap();
+    let width = usize::from(crate::bytes::u16(header, 17, crate::Endianness::Big)?);
+    let height = usize::from(crate::bytes::u16(header, 19, crate::Endianness::Big)?);
 
-    let bytes = &bytes[null_position..];
+    let texture_type = crate::bytes::u8(header, 25)?;
+    let mut palette_count = 0;
+    let mut palette_offset = None;
 
-    let width = usize::from(u16::from_be_bytes(bytes[17..19].try_into().unwrap()));
-    let height = usize::from(u16::from_be_bytes(bytes[19..21].try_into().unwrap()));
-
-    let image_bytes = &bytes[33..];
-
-    let texture_type = bytes[25];
-    match texture_type {
+    let format = match texture_type {
         0x1 => {
-            let bit_count = bytes[27];
+            let bit_count = crate::bytes::u8(header, 27)?;
             match bit_count {
-                24 => crate::playstation_2::decode_rgb8(image_bytes, width, height),
-                _ => panic!(),
+                24 => Format::PlayStation2Rgb8,
+                _ => return Err(crate::Error::UnsupportedBitCount { bit_count }),
             }
         }
-        0x81 => crate::gamecube::decode_cmpr(image_bytes, width, height),
-        0x82 => crate::gamecube::decode_rgb5a3(image_bytes, width, height),
-        0x85 => crate::gamecube::decode_rgba8(image_bytes, width, height),
+        0x81 => Format::GameCubeCmpr,
+        0x82 => Format::GameCubeRgb5a3,
+        0x85 => Format::GameCubeRgba8,
         0x89 | 0x8A => {
-            let palette_count = usize::from(u16::from_be_bytes(bytes[21..23].try_into().unwrap()));
-            match palette_count {
-                16 => {
-                    let palette = &bytes[bytes.len() - 64..];
-                    crate::gamecube::decode_c4(image_bytes, width, height, palette)
-                }
-                256 => {
-                    let palette = &bytes[bytes.len() - 1024..];
-                    crate::gamecube::decode_c8(image_bytes, width, height, palette)
-                }
-                _ => panic!(),
-            }
+            palette_count = usize::from(crate::bytes::u16(header, 21, crate::Endianness::Big)?);
+            let format = match palette_count {
+                16 => Format::GameCubeC4,
+                256 => Format::GameCubeC8,
+                _ => return Err(crate::Error::UnsupportedPaletteCount { palette_count }),
+            };
+            palette_offset = Some(crate::bytes::last_offset(bytes, palette_count * 4)?);
+            format
         }
-        _ => panic!(),
-    }
+        _ => return Err(crate::Error::UnknownTextureType { texture_type }),
+    };
+
+    Ok(TextureInfo {
+        texture_type: Some(texture_type),
+        format,
+        width,
+        height,
+        palette_count,
+        data_offset: null_position + 33,
+        palette_offset,
+        rle_bit_count: None,
+    })
 }
 
-pub fn extract_gamecube_textures(textures_path: &std::path::Path, output_path: &std::path::Path) {
-    std::fs::create_dir_all(output_path).unwrap();
+pub fn xbox_texture_info(bytes: &[u8]) -> crate::Result<TextureInfo> {
+    let null_position = 12 + crate::bytes::null_position(bytes, 12)?;
 
-    let textures = std::fs::read(textures_path).unwrap();
+    let header = &bytes[null_position..];
 
-    let file_list = crate::arc::list_files(&textures, crate::Endianness::Big);
+    let width = usize::from(crate::bytes::u16(header, 17, crate::Endianness::Little)?);
+    let height = usize::from(crate::bytes::u16(header, 19, crate::Endianness::Little)?);
 
-    for (name, _, bytes) in file_list {
-        let image = convert_gamecube_texture(bytes);
-        crate::save_texture(image, &name, output_path, SPECULAR_FILE_NAMES.contains(&name.as_str()));
-    }
-}
+    let texture_type = crate::bytes::u8(header, 25)?;
+    let mut palette_count = 0;
+    let mut palette_offset = None;
 
-fn convert_xbox_texture(bytes: &[u8]) -> image::RgbaImage {
-    let bytes = &bytes[12..];
-    let null_position = bytes.iter().position(|x| *x == 0).unwrap();
-
-    let bytes = &bytes[null_position..];
-
-    let width = usize::from(u16::from_le_bytes(bytes[17..19].try_into().unwrap()));
-    let height = usize::from(u16::from_le_bytes(bytes[19..21].try_into().unwrap()));
-
-    let texture_type = bytes[25];
-    match texture_type {
-        0x8C => crate::xbox::decode_rgba8(&bytes[33..], width, height),
+    let format = match texture_type {
+        0x8C => Format::XboxRgba8,
         0x8D => {
-            let palette = &bytes[bytes.len() - 1024..];
-            crate::xbox::decode_c8(&bytes[33..], width, height, palette)
+            palette_count = 256;
+            palette_offset = Some(crate::bytes::last_offset(bytes, 1024)?);
+            Format::XboxC8
         }
-        0x8E => crate::xbox::decode_bc1(&bytes[33..], width, height),
-        0x8F => crate::xbox::decode_bc2(&bytes[33..], width, height),
-        0x90 => crate::xbox::decode_rgb5(&bytes[33..], width, height),
-        _ => panic!(),
-    }
-}
-
-pub fn extract_xbox_textures(textures_path: &std::path::Path, output_path: &std::path::Path) {
-    std::fs::create_dir_all(output_path).unwrap();
-
-    let textures = std::fs::read(textures_path).unwrap();
-
-    let file_list = crate::arc::list_files(&textures, crate::Endianness::Little);
-
-    for (name, _, bytes) in file_list {
-        let image = convert_xbox_texture(bytes);
-        crate::save_texture(image, &name, output_path, SPECULAR_FILE_NAMES.contains(&name.as_str()));
-    }
+        0x8E => Format::XboxBc1,
+        0x8F => Format::XboxBc2,
+        0x90 => Format::XboxRgb5,
+        _ => return Err(crate::Error::UnknownTextureType { texture_type }),
+    };
+
+    Ok(TextureInfo {
+        texture_type: Some(texture_type),
+        format,
+        width,
+        height,
+        palette_count,
+        data_offset: null_position + 33,
+        palette_offset,
+        rle_bit_count: None,
+    })
 }
 
-const SPECULAR_FILE_NAMES: [&str; 2106] = [
-    "_garbage",
-    "action_channel_010000",
-    "af_gl_biker_goggles",
-    "af_gl_biker_goggles_game",
-    "af_gl_biker_silver",
-    "af_gl_biker_silver_game",
-    "af_gl_celebrity_square_elton",
-    "af_gl_celebrity_square_elton_game",
-    "af_gl_gangster_sunglasses_big_round",
-    "af_gl_gangster_sunglasses_big_round_game",
-    "af_gl_generic_extra_02",
-    "af_gl_generic_extra_02_game",
-    "af_gl_generic_extra_04",
-    "af_gl_generic_extra_04_game",
-    "af_gl_generic_extra_05",
-    "af_gl_generic_extra_05_game",
-    "af_gl_generic_extra_06",
-    "af_gl_generic_extra_06_game",
-    "af_gl_generic_wayfarer",
-    "af_gl_generic_wayfarer_game",
-    "af_gl_hipster_pointy",
-    "af_gl_hipster_pointy_game",
-    "af_gl_hipster_round_opaque",
-    "af_gl_hipster_round_opaque_game",
-    "af_gl_neon_star",
-    "af_gl_neon_star_game",
-    "af_gl_skater_aviators",
-    "af_gl_skater_aviators_game",
-    "af_gl_skater_infinity",
-    "af_gl_skater_infinity_game",
-    "af_gl_visor",
-    "af_gl_visor_game",
-    "af_hh_african_curly",
-    "af_hh_african_curly_game",
-    "af_hh_biker_captains_hat",
-    "af_hh_biker_captains_hat_game",
-    "af_hh_biker_do_rag",
-    "af_hh_biker_do_rag_game",
-    "af_hh_biker_offshore_alpha",
-    "af_hh_biker_offshore_alpha_game",
-    "af_hh_celebrity_long",
-    "af_hh_celebrity_long_game",
-    "af_hh_celebrity_medium_sidetwists",
-    "af_hh_celebrity_medium_sidetwists_game",
-    "af_hh_dread_pigtails",
-    "af_hh_dread_pigtails_game",
-    "af_hh_fruity_buns",
-    "af_hh_fruity_buns_game",
-    "af_hh_gangster_fluffy_beret_hair",
-    "af_hh_gangster_fluffy_beret_hair_game",
-    "af_hh_gangster_longhair_cap",
-    "af_hh_gangster_longhair_cap_game",
-    "af_hh_generic",
-    "af_hh_generic_ballcap_dorag",
-    "af_hh_generic_ballcap_dorag_game",
-    "af_hh_generic_beanie",
-    "af_hh_generic_beanie_game",
-    "af_hh_generic_bighat",
-    "af_hh_generic_bighat_game",
-    "af_hh_generic_bob",
-    "af_hh_generic_bob_eartuck",
-    "af_hh_generic_bob_eartuck_game",
-    "af_hh_generic_bob_game",
-    "af_hh_generic_bucket",
-    "af_hh_generic_bucket_game",
-    "af_hh_generic_bun_side_twirl",
-    "af_hh_generic_bun_side_twirl_game",
-    "af_hh_generic_chopsticks",
-    "af_hh_generic_chopsticks_game",
-    "af_hh_generic_cowboy_hat",
-    "af_hh_generic_cowboy_hat_game",
-    "af_hh_generic_frizzle",
-    "af_hh_generic_frizzle_game",
-    "af_hh_generic_game",
-    "af_hh_generic_hair_wavy",
-    "af_hh_generic_hair_wavy_game",
-    "af_hh_generic_highlight_dreads",
-    "af_hh_generic_highlight_dreads_game",
-    "af_hh_generic_mohawk_brush",
-    "af_hh_generic_mohawk_brush_game",
-    "af_hh_generic_mohawk_spikes",
-    "af_hh_generic_mohawk_spikes_game",
-    "af_hh_generic_pigtails",
-    "af_hh_generic_pigtails_game",
-    "af_hh_generic_visor",
-    "af_hh_generic_visor_game",
-    "af_hh_hipster_artsy_beret",
-    "af_hh_hipster_artsy_beret_game",
-    "af_hh_hipster_bob",
-    "af_hh_hipster_bob_game",
-    "af_hh_hipster_medium_sidecombed",
-    "af_hh_hipster_medium_sidecombed_game",
-    "af_hh_hot_topic",
-    "af_hh_hot_topic_game",
-    "af_hh_med_bob_curl_alpha",
-    "af_hh_med_bob_curl_alpha_game",
-    "af_hh_medfreyed",
-    "af_hh_medfreyed_game",
-    "af_hh_musician_cap_canvas",
-    "af_hh_musician_cap_canvas_game",
-    "af_hh_musician_helmetflip",
-    "af_hh_musician_helmetflip_game",
-    "af_hh_musician_stringy_curl_wet",
-    "af_hh_musician_stringy_curl_wet_game",
-    "af_hh_neon_pigtail",
-    "af_hh_neon_pigtail_game",
-    "af_hh_noir_hair",
-    "af_hh_noir_hair_game",
-    "af_hh_noir_hat",
-    "af_hh_noir_hat_game",
-    "af_hh_npc_sushi",
-    "af_hh_npc_sushi_game",
-    "af_hh_punk_mohawk",
-    "af_hh_punk_mohawk_game",
-    "af_hh_punk_spike_down",
-    "af_hh_punk_spike_down_game",
-    "af_hh_punk_streaky_bangs",
-    "af_hh_punk_streaky_bangs_game",
-    "af_hh_roxie",
-    "af_hh_roxie_game",
-    "af_hh_skater_dread_mob",
-    "af_hh_skater_dread_mob_game",
-    "af_hh_skater_stringy_long",
-    "af_hh_skater_stringy_long_game",
-    "af_hh_skater_trucker_hat",
-    "af_hh_skater_trucker_hat_game",
-    "af_hh_wiseguy_jersey_poof",
-    "af_hh_wiseguy_jersey_poof_game",
-    "af_jw_beads_collar",
-    "af_jw_beads_collar_game",
-    "af_jw_beads_zen",
-    "af_jw_beads_zen_game",
-    "af_jw_belt_blue",
-    "af_jw_belt_blue_game",
-    "af_jw_belt_white",
-    "af_jw_belt_white_game",
-    "af_jw_belt_white_hipster",
-    "af_jw_belt_white_hipster_game",
-    "af_jw_belt_white_holes",
-    "af_jw_belt_white_holes_game",
-    "af_jw_biker_belt_buckle_oval_silver",
-    "af_jw_biker_belt_buckle_oval_silver_game",
-    "af_jw_biker_belt_buckle_square_gold_attach_60",
-    "af_jw_biker_belt_buckle_square_gold_attach_60_game",
-    "af_jw_biker_gold_studded_collar",
-    "af_jw_biker_gold_studded_collar_game",
-    "af_jw_bracelets_triple",
-    "af_jw_bracelets_triple_game",
-    "af_jw_celebrity_necklace_big_pearls",
-    "af_jw_celebrity_necklace_big_pearls_game",
-    "af_jw_celebrity_necklace_chimes",
-    "af_jw_celebrity_necklace_chimes_game",
-    "af_jw_celebrity_r_ear_beads_attach_10",
-    "af_jw_celebrity_r_ear_beads_attach_10_game",
-    "af_jw_celebrity_r_ear_chimes_attach_10",
-    "af_jw_celebrity_r_ear_chimes_attach_10_game",
-    "af_jw_gangster_bracer_jewelled",
-    "af_jw_gangster_bracer_jewelled_game",
-    "af_jw_gangster_necklace_tiny_beads",
-    "af_jw_gangster_necklace_tiny_beads_game",
-    "af_jw_generic_belt_buckle_silver_thinrim",
-    "af_jw_generic_belt_buckle_silver_thinrim_game",
-    "af_jw_generic_necklace_extra_01",
-    "af_jw_generic_necklace_extra_01_game",
-    "af_jw_generic_necklace_seashells",
-    "af_jw_generic_necklace_seashells_game",
-    "af_jw_generic_wristband_beaded",
-    "af_jw_generic_wristband_beaded_game",
-    "af_jw_hipster_belt_doublering_buckle",
-    "af_jw_hipster_belt_doublering_buckle_game",
-    "af_jw_hipster_bracelet_colorful_rings",
-    "af_jw_hipster_bracelet_colorful_rings_game",
-    "af_jw_musician_belt_tiled5",
-    "af_jw_musician_belt_tiled5_game",
-    "af_jw_musician_bracelet_double_pearl_attach_30",
-    "af_jw_musician_bracelet_double_pearl_attach_30_game",
-    "af_jw_neon_belt_buckle_panda",
-    "af_jw_neon_belt_buckle_panda_game",
-    "af_jw_neon_bracer",
-    "af_jw_neon_bracer_game",
-    "af_jw_neon_watch_flower",
-    "af_jw_neon_watch_flower_game",
-    "af_jw_newjersey",
-    "af_jw_newjersey_game",
-    "af_jw_punk_belt_studded",
-    "af_jw_punk_belt_studded_game",
-    "af_jw_punk_key_necklace",
-    "af_jw_punk_key_necklace_game",
-    "af_jw_punk_rubber_bracelet_left",
-    "af_jw_punk_rubber_bracelet_left_game",
-    "af_jw_punk_rubber_bracelet_right",
-    "af_jw_punk_rubber_bracelet_right_game",
-    "af_jw_skater_armband",
-    "af_jw_skater_armband_game",
-    "af_jw_skater_beads",
-    "af_jw_skater_beads_game",
-    "af_jw_skater_belt_buckle_swirl",
-    "af_jw_skater_belt_buckle_swirl_game",
-    "af_jw_skater_belt_buckle_v8",
-    "af_jw_skater_belt_buckle_v8_game",
-    "af_jw_skater_leather_bracer",
-    "af_jw_skater_leather_bracer_game",
-    "af_jw_wiseguy_pearl_earring_left_attach_20",
-    "af_jw_wiseguy_pearl_earring_left_attach_20_game",
-    "af_jw_wiseguy_pearl_earring_right_attach_10",
-    "af_jw_wiseguy_pearl_earring_right_attach_10_game",
-    "af_lb_adriana",
-    "af_lb_adriana_game",
-    "af_lb_biker_daisy_dukes",
-    "af_lb_biker_daisy_dukes_game",
-    "af_lb_biker_leather_chaps",
-    "af_lb_biker_leather_chaps_game",
-    "af_lb_biker_leatherpants_kneepads",
-    "af_lb_biker_leatherpants_kneepads_game",
-    "af_lb_celebrity_skirt_long_sidesplit",
-    "af_lb_celebrity_skirt_long_sidesplit_game",
-    "af_lb_celebrity_skirt_long_sidesplit_sparkle",
-    "af_lb_celebrity_skirt_long_sidesplit_sparkle_game",
-    "af_lb_celebrity_skirt_sideslits",
-    "af_lb_celebrity_skirt_sideslits_game",
-    "af_lb_gangster_baggy_lowhanging",
-    "af_lb_gangster_baggy_lowhanging_game",
-    "af_lb_gangster_baggy_lowhanging_thong",
-    "af_lb_gangster_baggy_lowhanging_thong_game",
-    "af_lb_gangster_chinos_hvy",
-    "af_lb_gangster_chinos_hvy_game",
-    "af_lb_gangster_jeans_bellbottoms",
-    "af_lb_gangster_jeans_bellbottoms_game",
-    "af_lb_gangster_very_short_skirt",
-    "af_lb_gangster_very_short_skirt_game",
-    "af_lb_generic_jeans",
-    "af_lb_generic_jeans_game",
-    "af_lb_generic_jeans_long",
-    "af_lb_generic_jeans_long_game",
-    "af_lb_generic_jeans_long_hvy",
-    "af_lb_generic_jeans_long_hvy_game",
-    "af_lb_generic_skirt_conservative",
-    "af_lb_generic_skirt_conservative_game",
-    "af_lb_hipster_capri_pants",
-    "af_lb_hipster_capri_pants_game",
-    "af_lb_hipster_pants_tight",
-    "af_lb_hipster_pants_tight_game",
-    "af_lb_hipster_retro_forties",
-    "af_lb_hipster_retro_forties_game",
-    "af_lb_jeans_bell_bottoms",
-    "af_lb_jeans_bell_bottoms_game",
-    "af_lb_miniskirt_apron_stripe_tights",
-    "af_lb_miniskirt_apron_stripe_tights_game",
-    "af_lb_musician_chinos_hvy",
-    "af_lb_musician_chinos_hvy_game",
-    "af_lb_musician_gogo",
-    "af_lb_musician_gogo_game",
-    "af_lb_musician_pants_suede_sash",
-    "af_lb_musician_pants_suede_sash_game",
-    "af_lb_musician_skirt_short_shinguards",
-    "af_lb_musician_skirt_short_shinguards_game",
-    "af_lb_neon_chinos_hvy",
-    "af_lb_neon_chinos_hvy_game",
-    "af_lb_punk_leather_thigh_high",
-    "af_lb_punk_leather_thigh_high_game",
-    "af_lb_punk_tights_checker",
-    "af_lb_punk_tights_checker_game",
-    "af_lb_punk_tu_tu",
-    "af_lb_punk_tu_tu_game",
-    "af_lb_raveskirtpants",
-    "af_lb_raveskirtpants_game",
-    "af_lb_roxie",
-    "af_lb_roxie_game",
-    "af_lb_skater_cargo_shorts_tube_socks",
-    "af_lb_skater_cargo_shorts_tube_socks_game",
-    "af_lb_skaters_pants_camo",
-    "af_lb_skaters_pants_camo_game",
-    "af_lb_skill_mental",
-    "af_lb_skill_mental_game",
-    "af_lb_skill_mental_mat2",
-    "af_lb_skill_mental_mat2_game",
-    "af_lb_skirt_mini_plaid",
-    "af_lb_skirt_mini_plaid_game",
-    "af_lb_swim_bottom_thong",
-    "af_lb_swim_bottom_thong_game",
-    "af_lb_tight_pattern_heart",
-    "af_lb_tight_pattern_heart_game",
-    "af_lb_wiseguy_leather_mini",
-    "af_lb_wiseguy_leather_mini_game",
-    "af_sh_biker_boots_squaretoe",
-    "af_sh_biker_boots_squaretoe_game",
-    "af_sh_biker_cowgirl_ornate",
-    "af_sh_biker_cowgirl_ornate_game",
-    "af_sh_biker_reinforced",
-    "af_sh_biker_reinforced_game",
-    "af_sh_boots_combat_black",
-    "af_sh_boots_combat_black_game",
-    "af_sh_boots_gogo",
-    "af_sh_boots_gogo_game",
-    "af_sh_boots_plated",
-    "af_sh_boots_plated_game",
-    "af_sh_celebrity_sandal_long_straps",
-    "af_sh_celebrity_sandal_long_straps_game",
-    "af_sh_celebrity_shoe_medium_heel",
-    "af_sh_celebrity_shoe_medium_heel_game",
-    "af_sh_clodhopper",
-    "af_sh_clodhopper_game",
-    "af_sh_froot_boots",
-    "af_sh_froot_boots_game",
-    "af_sh_gangster_sharp_heel_laced_boots",
-    "af_sh_gangster_sharp_heel_laced_boots_game",
-    "af_sh_gangster_sneakers_normal",
-    "af_sh_gangster_sneakers_normal_game",
-    "af_sh_gangster_sneakers_thin",
-    "af_sh_gangster_sneakers_thin_game",
-    "af_sh_generic_sandal_pointy",
-    "af_sh_generic_sandal_pointy_game",
-    "af_sh_generic_sneakers",
-    "af_sh_generic_sneakers_game",
-    "af_sh_hipster_big_tongue_heels",
-    "af_sh_hipster_big_tongue_heels_game",
-    "af_sh_hipster_boots_wrinkled",
-    "af_sh_hipster_boots_wrinkled_game",
-    "af_sh_hipster_rubbertoe_canvas",
-    "af_sh_hipster_rubbertoe_canvas_game",
-    "af_sh_ho",
-    "af_sh_ho_game",
-    "af_sh_musician_patent_gogo",
-    "af_sh_musician_patent_gogo_game",
-    "af_sh_musician_sandals_high_heel",
-    "af_sh_musician_sandals_high_heel_game",
-    "af_sh_musician_sandles",
-    "af_sh_musician_sandles_game",
-    "af_sh_punk_combat_thick",
-    "af_sh_punk_combatboots",
-    "af_sh_punk_combatboots_game",
-    "af_sh_punk_pilgrim_square",
-    "af_sh_punk_pilgrim_square_game",
-    "af_sh_roxie",
-    "af_sh_roxie_game",
-    "af_sh_sandals01",
-    "af_sh_sandals01_game",
-    "af_sh_skater_sneakers_laser_strips",
-    "af_sh_skater_sneakers_laser_strips_game",
-    "af_sh_skater_sneakers_swoosh_retro",
-    "af_sh_skater_sneakers_swoosh_retro_game",
-    "af_sh_skaters_fip_flops",
-    "af_sh_skaters_fip_flops_game",
-    "af_sh_wiseguy_stilletto",
-    "af_sh_wiseguy_stilletto_game",
-    "af_skin_asian",
-    "af_skin_asian_game",
-    "af_skin_brown",
-    "af_skin_brown_game",
-    "af_skin_caucasian",
-    "af_skin_caucasian_fishnets",
-    "af_skin_caucasian_fishnets_game",
-    "af_skin_caucasian_game",
-    "af_skin_caucasian_hires",
-    "af_skin_caucasian_hires_game",
-    "af_skin_darkbrown",
-    "af_skin_darkbrown_game",
-    "af_skin_pale",
-    "af_skin_pale_game",
-    "af_skin_swap_01",
-    "af_skin_swap_01_game",
-    "af_skin_swap_02",
-    "af_skin_swap_02_game",
-    "af_skin_swap_03",
-    "af_skin_swap_03_game",
-    "af_skin_swap_04",
-    "af_skin_swap_04_game",
-    "af_skin_swap_05",
-    "af_skin_swap_05_game",
-    "af_skin_swap_06",
-    "af_skin_swap_06_game",
-    "af_skin_swap_07",
-    "af_skin_swap_07_game",
-    "af_skin_swap_08",
-    "af_skin_swap_08_game",
-    "af_skin_swap_09",
-    "af_skin_swap_09_game",
-    "af_skin_swap_10",
-    "af_skin_swap_10_game",
-    "af_skin_swap_11",
-    "af_skin_swap_11_game",
-    "af_skin_swap_12",
-    "af_skin_swap_12_game",
-    "af_ub_adriana",
-    "af_ub_adriana_game",
-    "af_ub_adriana_heavy",
-    "af_ub_adriana_heavy_game",
-    "af_ub_baremidriff_devil",
-    "af_ub_baremidriff_devil_game",
-    "af_ub_biker_leather_jacket_stripe_shoulder_pads",
-    "af_ub_biker_leather_jacket_stripe_shoulder_pads_game",
-    "af_ub_biker_slut_fringe",
-    "af_ub_biker_slut_fringe_game",
-    "af_ub_biker_vest_gloves",
-    "af_ub_biker_vest_gloves_game",
-    "af_ub_biker_vest_gloves_hvy",
-    "af_ub_biker_vest_gloves_hvy_game",
-    "af_ub_celebrity_dresstop_revealing_split",
-    "af_ub_celebrity_dresstop_revealing_split_game",
-    "af_ub_celebrity_dresstop_revealing_split_sparkle",
-    "af_ub_celebrity_dresstop_revealing_split_sparkle_game",
-    "af_ub_coat_leather_vneck",
-    "af_ub_coat_leather_vneck_game",
-    "af_ub_gangster_bare_midriff_revealing_jacket",
-    "af_ub_gangster_bare_midriff_revealing_jacket_game",
-    "af_ub_gangster_jacket_fur_collar_tshirt",
-    "af_ub_gangster_jacket_fur_collar_tshirt_game",
-    "af_ub_gangster_jacket_fur_collar_tshirt_hvy",
-    "af_ub_gangster_jewelled_bra",
-    "af_ub_gangster_jewelled_bra_game",
-    "af_ub_generic_camisole",
-    "af_ub_generic_camisole_game",
-    "af_ub_generic_crewneck_tanktop",
-    "af_ub_generic_crewneck_tanktop_game",
-    "af_ub_generic_shirt_2tone",
-    "af_ub_generic_shirt_2tone_game",
-    "af_ub_generic_tanktop",
-    "af_ub_generic_tanktop_game",
-    "af_ub_haltertop",
-    "af_ub_haltertop_game",
-    "af_ub_hipster_retro_forties",
-    "af_ub_hipster_retro_forties_game",
-    "af_ub_hipster_shirt_elbowlength_scarf_gloves",
-    "af_ub_hipster_shirt_elbowlength_scarf_gloves_game",
-    "af_ub_hipster_tshirt_shoulder_exposed",
-    "af_ub_hipster_tshirt_shoulder_exposed_game",
-    "af_ub_leather_short",
-    "af_ub_leather_short_game",
-    "af_ub_musician_barewaist_jacket",
-    "af_ub_musician_barewaist_jacket_game",
-    "af_ub_musician_barewaist_jacket_hvy",
-    "af_ub_musician_barewaist_jacket_hvy_game",
-    "af_ub_musician_gogo",
-    "af_ub_musician_gogo_game",
-    "af_ub_musician_tanktop_sequin",
-    "af_ub_musician_tanktop_sequin_game",
-    "af_ub_pj_pink",
-    "af_ub_pj_pink_game",
-    "af_ub_punk_corset",
-    "af_ub_punk_corset_game",
-    "af_ub_punk_elbowlength_hvy",
-    "af_ub_punk_elbowlength_hvy_game",
-    "af_ub_punk_straps_miniskirt",
-    "af_ub_punk_straps_miniskirt_game",
-    "af_ub_punk_tshirt_skull_game",
-    "af_ub_shirt_elbowlength_hvy",
-    "af_ub_shirt_elbowlength_hvy_game",
-    "af_ub_skater_shirt_shortsleeve_tanktop_hvy",
-    "af_ub_skater_shirt_shortsleeve_tanktop_hvy_game",
-    "af_ub_skater_tanktop",
-    "af_ub_skater_tanktop_game",
-    "af_ub_skater_tshirt_short_sleeves",
-    "af_ub_skater_tshirt_short_sleeves_game",
-    "af_ub_skill_mental",
-    "af_ub_skill_mental_game",
-    "af_ub_skill_mental_mat3",
-    "af_ub_skill_mental_mat3_game",
-    "af_ub_skill_physical",
-    "af_ub_skill_physical_game",
-    "af_ub_swim_top_thong",
-    "af_ub_swim_top_thong_game",
-    "af_ub_tank_apron_bracelet",
-    "af_ub_tank_apron_bracelet_game",
-    "af_ub_wiseguy_fur_tank",
-    "af_ub_wiseguy_fur_tank_game",
-    "air_duct_01",
-    "airconditioner",
-    "airduct_rusty",
-    "am_cop_boots",
-    "am_cop_boots_game",
-    "am_cop_gearbelt",
-    "am_cop_gearbelt_game",
-    "am_cop_gearbelt_leather",
-    "am_cop_gearbelt_leather_game",
-    "am_fh_biggoatee",
-    "am_fh_biggoatee_game",
-    "am_fh_biker_mustache_handlebar",
-    "am_fh_biker_mustache_handlebar_game",
-    "am_fh_brimley",
-    "am_fh_brimley_game",
-    "am_fh_generic_extra_05",
-    "am_fh_generic_extra_05_game",
-    "am_fh_generic_full_beard",
-    "am_fh_generic_full_beard_game",
-    "am_fh_godat",
-    "am_fh_godat_game",
-    "am_fh_grizzley",
-    "am_fh_grizzley_game",
-    "am_fh_humphrey",
-    "am_fh_humphrey_game",
-    "am_fh_lincoln",
-    "am_fh_lincoln_game",
-    "am_fh_neon_chin_braid",
-    "am_fh_neon_chin_braid_game",
-    "am_gl_biker_goggles",
-    "am_gl_biker_goggles_game",
-    "am_gl_biker_silver_narrow",
-    "am_gl_biker_silver_narrow_game",
-    "am_gl_bling_watch",
-    "am_gl_bling_watch_game",
-    "am_gl_bouncer_sun",
-    "am_gl_bouncer_sun_game",
-    "am_gl_celebrity_monocle",
-    "am_gl_celebrity_monocle_game",
-    "am_gl_generic_extra_04",
-    "am_gl_generic_extra_04_game",
-    "am_gl_generic_extra_05",
-    "am_gl_generic_extra_05_game",
-    "am_gl_generic_extra_06",
-    "am_gl_generic_extra_06_game",
-    "am_gl_gold_cube_necklace_pendant",
-    "am_gl_gold_cube_necklace_pendant_game",
-    "am_gl_gold_necklace_pendant",
-    "am_gl_gold_necklace_pendant_game",
-    "am_gl_hipster_thin_flat_nose_bridge",
-    "am_gl_hipster_thin_flat_nose_bridge_game",
-    "am_gl_musician_oval_thickrims",
-    "am_gl_musician_oval_thickrims_game",
-    "am_gl_neon_bugeyes",
-    "am_gl_neon_bugeyes_game",
-    "am_gl_skater_goggles",
-    "am_gl_skater_goggles_game",
-    "am_hh_african_curly",
-    "am_hh_african_curly_game",
-    "am_hh_baseball_cap",
-    "am_hh_baseball_cap_game",
-    "am_hh_biker_bandana",
-    "am_hh_biker_bandana_game",
-    "am_hh_biker_flame",
-    "am_hh_biker_flame_game",
-    "am_hh_biker_kaiserhelmet",
-    "am_hh_biker_kaiserhelmet_game",
-    "am_hh_ca_skinhead",
-    "am_hh_ca_skinhead_game",
-    "am_hh_celebrity_fedora_do_rag",
-    "am_hh_celebrity_fedora_do_rag_game",
-    "am_hh_celebrity_formal_parted",
-    "am_hh_celebrity_formal_parted_game",
-    "am_hh_collander",
-    "am_hh_collander_game",
-    "am_hh_dreads",
-    "am_hh_dreads_game",
-    "am_hh_fountain_dreads",
-    "am_hh_fountain_dreads_game",
-    "am_hh_gangster_do_rag_long",
-    "am_hh_gangster_do_rag_long_game",
-    "am_hh_generic_afro_tied",
-    "am_hh_generic_afro_tied_bandana",
-    "am_hh_generic_afro_tied_bandana_game",
-    "am_hh_generic_afro_tied_game",
-    "am_hh_generic_ballcap_dorag",
-    "am_hh_generic_ballcap_dorag_game",
-    "am_hh_generic_buzz",
-    "am_hh_generic_cap_back",
-    "am_hh_generic_cap_back_game",
-    "am_hh_generic_casual_dreads",
-    "am_hh_generic_casual_dreads_game",
-    "am_hh_generic_cornrows",
-    "am_hh_generic_cornrows_game",
-    "am_hh_generic_cowboyhat",
-    "am_hh_generic_cowboyhat_game",
-    "am_hh_generic_highlight_pompdour",
-    "am_hh_generic_highlight_pompdour_game",
-    "am_hh_generic_spikes",
-    "am_hh_generic_spikes_game",
-    "am_hh_generic_visor_bball",
-    "am_hh_generic_visor_bball_game",
-    "am_hh_hair_hat_cop",
-    "am_hh_hair_hat_cop_game",
-    "am_hh_hipster_cap_soft",
-    "am_hh_hipster_cap_soft_game",
-    "am_hh_hipster_spiky_fringe",
-    "am_hh_hipster_spiky_fringe_game",
-    "am_hh_hipster_warhol",
-    "am_hh_hipster_warhol_game",
-    "am_hh_knit_cap",
-    "am_hh_knit_cap_game",
-    "am_hh_musician_cap_canvas",
-    "am_hh_musician_cap_canvas_game",
-    "am_hh_musician_hat_fedora_frotte",
-    "am_hh_musician_hat_fedora_frotte_game",
-    "am_hh_musician_hat_fedora_small",
-    "am_hh_musician_hat_fedora_small_game",
-    "am_hh_musician_short",
-    "am_hh_musician_short_game",
-    "am_hh_neon_antennae_braids",
-    "am_hh_neon_antennae_braids_game",
-    "am_hh_neon_dragonball_spikes",
-    "am_hh_neon_dragonball_spikes_game",
-    "am_hh_neon_hair_moppy",
-    "am_hh_neon_hair_moppy_game",
-    "am_hh_npc_JJ_pompadour",
-    "am_hh_npc_JJ_pompadour_game",
-    "am_hh_punk_mohawk_3spikes",
-    "am_hh_punk_mohawk_3spikes_game",
-    "am_hh_punk_spikes_long",
-    "am_hh_punk_spikes_long_game",
-    "am_hh_skater_ski_capt",
-    "am_hh_skater_ski_capt_game",
-    "am_hh_skater_trucker_hat",
-    "am_hh_skater_trucker_hat_game",
-    "am_hh_skater_trucker_hat_hair",
-    "am_hh_skater_trucker_hat_hair_game",
-    "am_hh_trucker_hat_blue",
-    "am_hh_trucker_hat_blue_game",
-    "am_hh_under_wool_beret",
-    "am_hh_under_wool_beret_game",
-    "am_hh_wiseguy_long",
-    "am_hh_wiseguy_long_game",
-    "am_hh_wool_beret",
-    "am_hh_wool_beret_game",
-    "am_jw_belt_black_leather_holes",
-    "am_jw_belt_black_leather_holes_game",
-    "am_jw_belt_brown",
-    "am_jw_belt_brown_game",
-    "am_jw_belt_green",
-    "am_jw_belt_green_game",
-    "am_jw_biker_belt_brown",
-    "am_jw_biker_belt_brown_game",
-    "am_jw_biker_belt_buckle_country_oval_attach_60",
-    "am_jw_biker_belt_buckle_country_oval_attach_60_game",
-    "am_jw_biker_belt_buckle_eagle",
-    "am_jw_biker_belt_buckle_eagle_game",
-    "am_jw_biker_necklace_chain_big",
-    "am_jw_biker_necklace_chain_big_game",
-    "am_jw_celebrity_rolex",
-    "am_jw_celebrity_rolex_game",
-    "am_jw_celebrity_silver_horn",
-    "am_jw_celebrity_silver_horn_game",
-    "am_jw_gangster_chain_thick",
-    "am_jw_gangster_chain_thick_game",
-    "am_jw_gangster_dogtag_necklace",
-    "am_jw_gangster_dogtag_necklace_game",
-    "am_jw_gangster_necklace_doublephat",
-    "am_jw_gangster_necklace_doublephat_game",
-    "am_jw_gangster_studded_wristband",
-    "am_jw_gangster_studded_wristband_game",
-    "am_jw_gangster_terrycloth_wristband",
-    "am_jw_gangster_terrycloth_wristband_game",
-    "am_jw_generic_earring_japanese_left",
-    "am_jw_generic_earring_japanese_left_game",
-    "am_jw_generic_earring_japanese_right",
-    "am_jw_generic_earring_japanese_right_game",
-    "am_jw_generic_wristwatch",
-    "am_jw_generic_wristwatch_game",
-    "am_jw_goldmap",
-    "am_jw_goldmap_game",
-    "am_jw_hipster_belt_buckle_pinlock",
-    "am_jw_hipster_belt_buckle_pinlock_game",
-    "am_jw_hipster_belt_buckle_rust",
-    "am_jw_hipster_belt_buckle_rust_game",
-    "am_jw_hipster_bracelet_sweatbands",
-    "am_jw_hipster_bracelet_sweatbands_game",
-    "am_jw_hipster_watch_leather",
-    "am_jw_hipster_watch_leather_game",
-    "am_jw_necklace_chase_01",
-    "am_jw_necklace_chase_01_game",
-    "am_jw_neon_wrist_sweatband_attach_50",
-    "am_jw_neon_wrist_sweatband_attach_50_game",
-    "am_jw_npc_city_key",
-    "am_jw_npc_city_key_game",
-    "am_jw_punk_belt_3studs",
-    "am_jw_punk_belt_3studs_game",
-    "am_jw_punk_belt_bullets",
-    "am_jw_punk_belt_bullets_game",
-    "am_jw_punk_spikecollar",
-    "am_jw_punk_spikecollar_game",
-    "am_jw_skater_belt_buckle_double_rings_attach_60",
-    "am_jw_skater_belt_buckle_double_rings_attach_60_game",
-    "am_jw_skater_belt_buckle_swiss_army",
-    "am_jw_skater_belt_buckle_swiss_army_game",
-    "am_lb_belt_brassbuckle",
-    "am_lb_belt_brassbuckle_game",
-    "am_lb_biker_leatherpants_pads",
-    "am_lb_biker_leatherpants_pads_game",
-    "am_lb_celebrity_chinese_slacks",
-    "am_lb_celebrity_chinese_slacks_game",
-    "am_lb_celebrity_pants_zoot_suit",
-    "am_lb_celebrity_pants_zoot_suit_game",
-    "am_lb_cop_pants",
-    "am_lb_cop_pants_game",
-    "am_lb_gangster_fatigues_tetris",
-    "am_lb_gangster_fatigues_tetris_game",
-    "am_lb_gangster_jeans_threadings",
-    "am_lb_gangster_jeans_threadings_game",
-    "am_lb_gangster_sagpants",
-    "am_lb_gangster_sagpants_game",
-    "am_lb_generic_jeans",
-    "am_lb_generic_jeans_game",
-    "am_lb_generic_pants_baggy",
-    "am_lb_generic_pants_baggy_game",
-    "am_lb_hipster_long_jeans",
-    "am_lb_hipster_long_jeans_game",
-    "am_lb_hipster_pants_supertight",
-    "am_lb_hipster_pants_supertight_game",
-    "am_lb_hipster_suit_pants_cuffs",
-    "am_lb_hipster_suit_pants_cuffs_game",
-    "am_lb_jeans_cuff",
-    "am_lb_jeans_cuff_game",
-    "am_lb_jeans_flare",
-    "am_lb_jeans_flare_game",
-    "am_lb_jeans_sag",
-    "am_lb_jeans_sag_game",
-    "am_lb_jeans_snakeskin",
-    "am_lb_jeans_snakeskin_game",
-    "am_lb_musician_pants_baggy_oriental",
-    "am_lb_musician_pants_baggy_oriental_game",
-    "am_lb_musician_pants_kneehigh_widebrimmed",
-    "am_lb_musician_pants_kneehigh_widebrimmed_game",
-    "am_lb_musician_pants_pinstripe",
-    "am_lb_musician_pants_pinstripe_game",
-    "am_lb_neon_pants_kneehigh_cuffed",
-    "am_lb_neon_pants_kneehigh_cuffed_game",
-    "am_lb_neon_scrolling_strips",
-    "am_lb_neon_scrolling_strips_game",
-    "am_lb_neon_shorts_over_pants",
-    "am_lb_neon_shorts_over_pants_game",
-    "am_lb_npc_JJ_overall",
-    "am_lb_npc_JJ_overall_game",
-    "am_lb_pants_tornleft",
-    "am_lb_pants_tornleft_game",
-    "am_lb_punk_blackjeans_bailey",
-    "am_lb_punk_blackjeans_bailey_game",
-    "am_lb_punk_hellastrappy",
-    "am_lb_punk_hellastrappy_game",
-    "am_lb_punk_leader_pants_fatigue_hole",
-    "am_lb_punk_leader_pants_fatigue_hole_game",
-    "am_lb_pyjamas",
-    "am_lb_pyjamas_game",
-    "am_lb_shorts_swimming",
-    "am_lb_shorts_swimming_game",
-    "am_lb_shorts_torn",
-    "am_lb_shorts_torn_game",
-    "am_lb_skater_cargo_aladdin_tube_socks",
-    "am_lb_skater_cargo_aladdin_tube_socks_game",
-    "am_lb_skill_mental",
-    "am_lb_skill_mental_game",
-    "am_lb_skill_mental_mat2",
-    "am_lb_skill_mental_mat2_game",
-    "am_lb_skill_physical",
-    "am_lb_skill_physical_game",
-    "am_lb_slacks_pleated",
-    "am_lb_slacks_pleated_game",
-    "am_lb_slacks_wiseguy",
-    "am_lb_slacks_wiseguy_game",
-    "am_lb_wiseguytough_pants",
-    "am_lb_wiseguytough_pants_game",
-    "am_sh_biker_boots_elegant",
-    "am_sh_biker_boots_elegant_game",
-    "am_sh_biker_boots_large_buckle",
-    "am_sh_biker_boots_large_buckle_game",
-    "am_sh_biker_boots_sharptoe",
-    "am_sh_biker_boots_sharptoe_game",
-    "am_sh_boots_blue_combat",
-    "am_sh_boots_blue_combat_game",
-    "am_sh_boots_cowboy_black",
-    "am_sh_boots_cowboy_black_game",
-    "am_sh_celebrity_loafer_square",
-    "am_sh_celebrity_loafer_square_game",
-    "am_sh_celebrity_pointy_disco_pimpin",
-    "am_sh_celebrity_pointy_disco_pimpin_game",
-    "am_sh_flanders",
-    "am_sh_flanders_game",
-    "am_sh_gangster_bball_hightops",
-    "am_sh_gangster_bball_hightops_game",
-    "am_sh_gangster_sneakers_strap",
-    "am_sh_gangster_sneakers_strap_game",
-    "am_sh_generic_sneakers",
-    "am_sh_generic_sneakers_flat",
-    "am_sh_generic_sneakers_flat_game",
-    "am_sh_generic_sneakers_game",
-    "am_sh_hipster_leather_pincushion",
-    "am_sh_hipster_leather_pincushion_game",
-    "am_sh_hipster_rubbertoe_canvas_bbsneakers",
-    "am_sh_hipster_rubbertoe_canvas_bbsneakers_game",
-    "am_sh_hipster_sneakers_slick_bootlike",
-    "am_sh_hipster_sneakers_slick_bootlike_game",
-    "am_sh_leather_curl",
-    "am_sh_leather_curl_game",
-    "am_sh_loafers",
-    "am_sh_loafers_game",
-    "am_sh_musician_boots_laced",
-    "am_sh_musician_boots_laced_game",
-    "am_sh_musician_boots_smooth",
-    "am_sh_musician_boots_smooth_game",
-    "am_sh_musician_loafers_sturdy",
-    "am_sh_musician_loafers_sturdy_game",
-    "am_sh_musician_sneakers_thicksoles",
-    "am_sh_musician_sneakers_thicksoles_game",
-    "am_sh_neon_moonboots",
-    "am_sh_neon_moonboots_game",
-    "am_sh_neon_scrolling_strips",
-    "am_sh_neon_scrolling_strips_game",
-    "am_sh_neon_sneakers_puffy",
-    "am_sh_neon_sneakers_puffy_game",
-    "am_sh_npc_JJ_workboots",
-    "am_sh_npc_JJ_workboots_game",
-    "am_sh_punk_combatboots_bailey",
-    "am_sh_punk_combatboots_bailey_game",
-    "am_sh_punk_featurelessboots",
-    "am_sh_punk_featurelessboots_game",
-    "am_sh_shoes_skater",
-    "am_sh_shoes_skater_game",
-    "am_sh_skater_sneakers_3_strips",
-    "am_sh_skater_sneakers_3_strips_game",
-    "am_sh_skater_vans",
-    "am_sh_skater_vans_game",
-    "am_sh_skill_physical",
-    "am_sh_skill_physical_game",
-    "am_sh_wiseguytough_shoes",
-    "am_sh_wiseguytough_shoes_game",
-    "am_skin_asian",
-    "am_skin_asian_game",
-    "am_skin_brown",
-    "am_skin_brown_game",
-    "am_skin_caucasian",
-    "am_skin_caucasian_game",
-    "am_skin_caucasian_hires",
-    "am_skin_caucasian_hires_game",
-    "am_skin_caucasian_npc",
-    "am_skin_caucasian_tatoos",
-    "am_skin_caucasian_tatoos_game",
-    "am_skin_darkbrown",
-    "am_skin_darkbrown_game",
-    "am_skin_darkbrown_npc",
-    "am_skin_pale",
-    "am_skin_pale_game",
-    "am_skin_swap_01",
-    "am_skin_swap_01_game",
-    "am_skin_swap_02",
-    "am_skin_swap_02_game",
-    "am_skin_swap_03",
-    "am_skin_swap_03_game",
-    "am_skin_swap_04",
-    "am_skin_swap_04_game",
-    "am_skin_swap_05",
-    "am_skin_swap_05_game",
-    "am_skin_swap_06",
-    "am_skin_swap_06_game",
-    "am_skin_swap_07",
-    "am_skin_swap_07_game",
-    "am_skin_swap_08",
-    "am_skin_swap_08_game",
-    "am_skin_swap_09",
-    "am_skin_swap_09_game",
-    "am_skin_swap_10",
-    "am_skin_swap_10_game",
-    "am_skin_swap_11",
-    "am_skin_swap_11_game",
-    "am_skin_swap_12",
-    "am_skin_swap_12_game",
-    "am_ub_bare_nipplerings",
-    "am_ub_bare_nipplerings_game",
-    "am_ub_biker_flamevest",
-    "am_ub_biker_flamevest_game",
-    "am_ub_biker_leather_jacket_shoulder_pads",
-    "am_ub_biker_leather_jacket_shoulder_pads_game",
-    "am_ub_biker_vest_wrench",
-    "am_ub_biker_vest_wrench_game",
-    "am_ub_celebrity_chinese_formal",
-    "am_ub_celebrity_chinese_formal_game",
-    "am_ub_celebrity_jacket_zoot_suit",
-    "am_ub_celebrity_jacket_zoot_suit_game",
-    "am_ub_coat_suede_open",
-    "am_ub_coat_suede_open_game",
-    "am_ub_cop_shirt",
-    "am_ub_cop_shirt_game",
-    "am_ub_gangster_hooded_sweater_tanktop",
-    "am_ub_gangster_hooded_sweater_tanktop_game",
-    "am_ub_gangster_tank",
-    "am_ub_gangster_tank_game",
-    "am_ub_gangster_wifebeater",
-    "am_ub_gangster_wifebeater_game",
-    "am_ub_generic_hockey_shirt",
-    "am_ub_generic_hockey_shirt_game",
-    "am_ub_generic_shirt_2tone",
-    "am_ub_generic_shirt_2tone_game",
-    "am_ub_generic_tanktop",
-    "am_ub_generic_tanktop_game",
-    "am_ub_generic_tshirt",
-    "am_ub_generic_tshirt_game",
-    "am_ub_hawaiianshirt_open",
-    "am_ub_hawaiianshirt_open_game",
-    "am_ub_hipster_small_jacket",
-    "am_ub_hipster_small_jacket_game",
-    "am_ub_hipster_suit_jacket_loose_tie",
-    "am_ub_hipster_suit_jacket_loose_tie_game",
-    "am_ub_hipster_turtleneck_tight",
-    "am_ub_leather_ruffles",
-    "am_ub_leather_ruffles_game",
-    "am_ub_leather_spike_skull",
-    "am_ub_leather_spike_skull_game",
-    "am_ub_musician_jacket_oriental",
-    "am_ub_musician_jacket_oriental_game",
-    "am_ub_musician_jacket_striped_tie",
-    "am_ub_musician_jacket_striped_tie_game",
-    "am_ub_musician_jacket_turtleneck",
-    "am_ub_musician_jacket_turtleneck_game",
-    "am_ub_musician_shirt_untucked",
-    "am_ub_musician_shirt_untucked_game",
-    "am_ub_naked_cape",
-    "am_ub_naked_cape_game",
-    "am_ub_neon_jacket_over_suit_tie",
-    "am_ub_neon_jacket_over_suit_tie_game",
-    "am_ub_neon_scrolling_strips",
-    "am_ub_neon_scrolling_strips_game",
-    "am_ub_npc_JJ_overall",
-    "am_ub_npc_JJ_overall_game",
-    "am_ub_punk_hellabondage",
-    "am_ub_punk_hellabondage_game",
-    "am_ub_punk_leatherjacket_bailey",
-    "am_ub_punk_leatherjacket_bailey_game",
-    "am_ub_pyjamas",
-    "am_ub_pyjamas_game",
-    "am_ub_shirt_70s_fractal",
-    "am_ub_shirt_70s_fractal_game",
-    "am_ub_skater_camper_vest_layered_shirt",
-    "am_ub_skater_camper_vest_layered_shirt_game",
-    "am_ub_skater_flannel_tshirt",
-    "am_ub_skater_flannel_tshirt_game",
-    "am_ub_skill_mental",
-    "am_ub_skill_mental_game",
-    "am_ub_skill_mental_mat3",
-    "am_ub_skill_mental_mat3_game",
-    "am_ub_tshirt_cuff",
-    "am_ub_tshirt_cuff_game",
-    "am_ub_wiseguytough_jacket",
-    "am_ub_wiseguytough_jacket_game",
-    "appliance_bar",
-    "appliance_counterfeit",
-    "appliance_dishwasher_traditional",
-    "appliance_espresso_foundry",
-    "appliance_fridge_retro",
-    "appliance_grill_sausage",
-    "appliance_heliumbar",
-    "appliance_stove_x_stainless",
-    "aquarium_cas",
-    "aquarium_floor_01_parts",
-    "aquarium_floor_02_parts",
-    "aqueduct_02",
-    "aqueduct_02_cas",
-    "armoire_metal_industrial",
-    "art_floor_ad_frame",
-    "art_floor_ad_frame_02",
-    "art_floor_machine_claw",
-    "art_painting_back_2",
-    "art_sculpture_x_parrot",
-    "art_wall_back",
-    "art_wall_back_canvas",
-    "art_wall_back_cas",
-    "art_wall_band_poster",
-    "art_wall_frame_fancy",
-    "art_wall_frame_wood",
-    "art_wall_haight",
-    "art_wall_haight_2wide",
-    "art_wall_japanese_painting",
-    "art_wall_japanese_swords",
-    "art_wall_machine_eyetoy",
-    "art_wall_machine_eyetoy_cas",
-    "art_wall_machine_metalplate",
-    "art_wall_machine_metalplate_cas",
-    "art_wall_painting_01",
-    "art_wall_painting_02",
-    "art_wall_painting_03",
-    "art_wall_painting_landscape",
-    "art_wall_poker_dogs",
-    "art_wall_punk_flag",
-    "art_wall_punk_grafitti",
-    "art_wall_punk_grafitti_cas",
-    "art_wall_punk_pircing_cas",
-    "art_wall_vent_circular",
-    "art_wall_vent_circular_back",
-    "artsy_furniture_wood",
-    "background_city_floor",
-    "bed_double_canopy_ornate",
-    "bed_single_double_brass",
-    "bed_single_double_mission",
-    "bed_single_punk",
-    "bedsheet_cream",
-    "bedsheet_neonleopard",
-    "bike_frame_cas",
-    "biker_bottles1_cas",
-    "biker_bottles2_cas",
-    "biker_curtain_cas",
-    "biker_tires_cas",
-    "bikers_lp_cas",
-    "Billboard_Backdrop01",
-    "Billboard_Backdrop02",
-    "Billboard_Backdrop03",
-    "Billboard_Backdrop04",
-    "billboard_honda_white",
-    "billboard_light",
-    "billboard_verizon02_white",
-    "billboard_verizon02_white_02",
-    "billboard_verizon_darius",
-    "billboard_verizon_white",
-    "billboard_verizon_xam_stripes",
-    "black",
-    "black_cas",
-    "blackmarble",
-    "blackmarble_cas",
-    "blue_china_vase_room",
-    "bookshelf_mission",
-    "brick_yellow",
-    "building_01_filler",
-    "building_01_solid",
-    "building_02",
-    "building_03",
-    "building_06",
-    "building_07",
-    "building_11",
-    "building_12",
-    "building_13",
-    "building_14",
-    "building_14_filler",
-    "building_18",
-    "building_20",
-    "building_21",
-    "building_21stucco",
-    "building_22",
-    "building_23",
-    "building_25",
-    "building_26_solid",
-    "building_28_solid",
-    "building_31",
-    "building_31_solid",
-    "building_33_solid",
-    "building_34_solid",
-    "building_35_solid",
-    "building_36",
-    "building_37",
-    "building_39_solid",
-    "building_39a",
-    "building_40_solid",
-    "building_42_brickonly",
-    "building_44",
-    "building_45",
-    "building_46",
-    "building_roof_monkey",
-    "cabinet_armoire_deco",
-    "cabinet_armoire_flimsy",
-    "cabinet_armoire_flimsy_clothes",
-    "cabinet_armoire_plywood",
-    "cabinet_armoire_plywood2",
-    "cabinet_armoire_punk_base",
-    "cabinet_armoire_punk_base_cas",
-    "cabinet_armoire_punk_clothes",
-    "cabinet_armoire_punk_door",
-    "cabinet_armoire_punk_door_cas",
-    "carry_cutting_board_stage_1",
-    "carry_cutting_board_stage_2",
-    "carry_food_chinese",
-    "carry_food_tray",
-    "carry_gnome",
-    "carry_snack_chips",
-    "carry_soup_in_pan",
-    "carry_toasteroven_pot",
-    "carry_tray_boxes_cartons_etc",
-    "carry_tray_of_beans",
-    "carry_tv_dinner_box",
-    "cartoon_01",
-    "cartoon_02",
-    "cartoon_03",
-    "cartoon_04",
-    "cartoon_05",
-    "cartoon_06",
-    "cartoon_07",
-    "cartoon_08",
-    "cartoon_09",
-    "cartoon_10",
-    "cartoon_11",
-    "cartoon_12",
-    "cartoon_13",
-    "cartoon_14",
-    "cas_ceiling_floor_brighter",
-    "cas_couch_red_baked",
-    "cas_door",
-    "cas_edge_albumcovers",
-    "cas_edge_bottles",
-    "cas_edge_bottles_2",
-    "cas_edge_bulb",
-    "cas_edge_cardboard_box",
-    "cas_edge_ceiling",
-    "cas_edge_chair",
-    "cas_edge_chair_legs",
-    "cas_edge_general_wood_board",
-    "cas_edge_hot_topic_boots",
-    "cas_edge_noise_blue",
-    "cas_edge_noise_brown",
-    "cas_edge_street_filler",
-    "cas_edge_tshirts",
-    "cas_edge_wall_2",
-    "cas_edge_wall_3",
-    "cas_edge_wire",
-    "cas_ground",
-    "cas_neon_lights",
-    "cas_outside",
-    "cas_purse",
-    "cas_sky",
-    "cas_sky_blue",
-    "celebrities_bottles1_cas",
-    "celebrities_bottles2_cas",
-    "celebrities_floor_cas",
-    "celebrities_frame_cas",
-    "celebrities_wall_cas",
-    "celebrity_clothrack_cas",
-    "cement_aqueduct",
-    "cement_brown_seams",
-    "chopper_paint_blue",
-    "chopper_paint_flame",
-    "chopper_paint_green",
-    "chopper_paint_green_cas",
-    "chopper_paint_red",
-    "chopper_paint_red_cas",
-    "chopper_paint_silver",
-    "chrome_tool",
-    "concrete_46_floor",
-    "concrete_trim04",
-    "concrete_trim_assorted",
-    "conveyor_belt",
-    "count_blanc_bathroom_counter_face",
-    "count_blanc_bathroom_counter_side",
-    "count_blanc_bathroom_counter_top",
-    "counter_butcherblock",
-    "counter_c_kitchen_ikea",
-    "counter_commercial_side",
-    "counter_commercial_top",
-    "counter_corian_top",
-    "counter_industrial",
-    "counter_slat_board",
-    "counter_slat_board_top",
-    "counter_steel",
-    "counter_toolchest",
-    "counter_toolchest_cas",
-    "counter_wiseguys",
-    "counter_x_butcherblock",
-    "cozmo_brass",
-    "cozmo_celebrity_photos",
-    "cozmo_chrome",
-    "cozmo_chrome_cas",
-    "cozmo_door_glass_brass",
-    "cozmo_door_glass_brass_cas",
-    "cozmo_frame_back",
-    "cozmo_frame_front",
-    "cozmo_glass_03",
-    "cozmo_lamp_wall_sconce",
-    "cozmo_lamp_wall_sconce_cas",
-    "cozmo_metal_02",
-    "cozmo_metal_02_cas",
-    "cozmo_stall_leopard",
-    "cozmo_toilet_drain",
-    "cozmo_window_nightclub_tall_frame",
-    "cozmo_window_nightclub_tall_molding",
-    "cozmo_wood_01",
-    "cozmo_wood_01_cas",
-    "cozmo_wood_02",
-    "cozmo_wood_02_cas",
-    "cozmo_wood_03",
-    "cozmo_wood_molding_01",
-    "cozmo_wood_molding_01_cas",
-    "decorative_gnome_cheat",
-    "decorative_scroll_japanese_sabertiger",
-    "default_bottles1_cas",
-    "default_bottles2_cas",
-    "default_carpet_cas",
-    "default_cstand1_cas",
-    "default_cstand2_cas",
-    "default_cstand3_cas",
-    "default_Jayde_cas",
-    "default_pipes_cas",
-    "default_poster_cas",
-    "default_roof_cas",
-    "default_toys_cas",
-    "default_wall_cas",
-    "default_wallback_cas",
-    "default_wallmap_cas",
-    "directions_125_cas",
-    "dirt_01",
-    "dm_bulldog",
-    "door_barred",
-    "door_barred_cas",
-    "door_bouncer",
-    "door_club_steel_small",
-    "door_copper_glass",
-    "door_corrugated_color",
-    "door_corrugated_color_cas",
-    "door_elevator_ornate_gold_1",
-    "door_elevator_ornate_gold_2",
-    "door_elevator_ornate_gold_3",
-    "door_elevator_ornate_grate",
-    "door_fivepanel",
-    "door_gold_ornate",
-    "door_gold_ornate_cas",
-    "door_industrial01",
-    "door_industrial02",
-    "door_japanese",
-    "door_japanese02",
-    "door_metal_brokenwindow",
-    "door_metal_industrial_brushed_aluminum",
-    "door_metal_smallwindow",
-    "door_metal_warehouse",
-    "door_metalgarage_dirty",
-    "door_metalgarage_dirty02",
-    "door_rollup_02",
-    "drag_bike_green",
-    "drinkbar_biker",
-    "drinkbar_biker_bar",
-    "drinkbar_biker_bottle",
-    "drinkbar_biker_drinkglass",
-    "drinkbar_biker_flame",
-    "drinkbar_biker_shaker",
-    "drinkbar_biker_tray",
-    "drinkbar_tapioca",
-    "drinkbar_tapioca_shaker",
-    "drinkbar_tapioca_tank",
-    "drinkbar_wiseguy_bottle",
-    "drinkbar_wiseguy_carving",
-    "drinkbar_wiseguy_drinkglass",
-    "drinkbar_wiseguy_inlay",
-    "drinkbar_wiseguy_ribs",
-    "drinkbar_wiseguy_shaker",
-    "drinkbar_wiseguy_tray",
-    "drinkbar_wiseguy_trim",
-    "edge_wire_cas",
-    "electronics_arcade_scifi",
-    "electronics_arcade_scifi_inuse_loop1",
-    "electronics_arcade_scifi_inuse_loop2",
-    "electronics_arcade_scifi_on_loop1",
-    "electronics_arcade_scifi_ship",
-    "electronics_dancemachine",
-    "electronics_dancemachine_lit_blue",
-    "electronics_dancemachine_lit_red",
-    "electronics_dancemachine_marquee",
-    "electronics_dancemachine_screen_off",
-    "electronics_dancemachine_screen_on",
-    "electronics_dancing_element",
-    "electronics_dancing_element_particle",
-    "electronics_dancing_element_wheel",
-    "electronics_dancing_van",
-    "electronics_dancing_van_bling",
-    "electronics_dancing_van_boombox",
-    "electronics_ravetower_speakers",
-    "electronics_stereo",
-    "electronics_stereo_01",
-    "electronics_stereo_02",
-    "electronics_stereo_highend_01",
-    "electronics_stereo_jukebox_biker",
-    "electronics_tv_biker",
-    "electronics_tv_milkcrate",
-    "electronics_tv_plasma_1x3",
-    "electronics_vending_cantop",
-    "electronics_vending_drink",
-    "electronics_vending_drink_panels",
-    "electronics_vending_drink_redbullcan",
-    "electronics_vending_drink_redbullcan_02",
-    "electronics_vending_drink_redbullcan_cas",
-    "electronics_vending_drink_redbullcan_cas_02",
-    "electronics_vending_icecream",
-    "electronics_vending_icecream_cone_tile",
-    "electronics_vending_icecream_stripe",
-    "electronics_vending_redbull_panels",
-    "electronics_vending_redbull_panels_02",
-    "electronics_vending_redbull_stand",
-    "electronics_vending_redbull_stand_02",
-    "electronics_vending_snack",
-    "escalator_cas",
-    "espresso_copper",
-    "espresso_grill",
-    "eyetoy_billboard_01",
-    "eyetoy_billboard_01_cas",
-    "eyetoy_funframe_01",
-    "eyetoy_funframe_02",
-    "eyetoy_funframe_03",
-    "eyetoy_funframe_04",
-    "eyetoy_funframe_05",
-    "eyetoy_funframe_06",
-    "eyetoy_funframe_07",
-    "eyetoy_funframe_08",
-    "eyetoy_funframe_09",
-    "eyetoy_funframe_10",
-    "eyetoy_funframe_11",
-    "eyetoy_funframe_12",
-    "fa_jw_silver-hoop",
-    "fe_black",
-    "fe_floor_tile_street_edge",
-    "felt_blue",
-    "final-floor-tile-36",
-    "final-floor-tile-46",
-    "final_adult_male_nude",
-    "floor_carpet_peach",
-    "floor_checker_linoleum_2wide",
-    "floor_checker_linoleum_punk_cas",
-    "floor_editoggler",
-    "floor_skaters_cas",
-    "floor_stone_decorative",
-    "floor_tile_invisible",
-    "floor_tile_marble_black",
-    "floor_tile_street_edge",
-    "floor_tile_street_middle_left",
-    "floor_tile_street_middle_right",
-    "floortile_apt1_carpet_edging_h",
-    "floortile_apt1_carpet_edging_v",
-    "floortile_apt1_carpet_white",
-    "floortile_apt1_tile_outsidedark",
-    "floortile_apt1_tile_outsidelight",
-    "floortile_apt2_linoleum_checker",
-    "floortile_apt2_tile_bath",
-    "floortile_apt2_wood_old",
-    "floortile_apt3_linoleum_2x2",
-    "floortile_apt3_linoleum_diagonal",
-    "floortile_apt3_linoleum_diagonal_cas",
-    "floortile_apt3_linoleum_punk",
-    "floortile_apt3_tile_checker",
-    "floortile_apt_granite_checker",
-    "floortile_biker_barfloor",
-    "floortile_biker_blackrubber",
-    "floortile_biker_carpet_flame",
-    "floortile_biker_concrete",
-    "floortile_biker_concrete_cas",
-    "floortile_biker_metal_diamond_cas",
-    "floortile_biker_metal_diamond_old",
-    "floortile_cozmo_carpet_pattern",
-    "floortile_cozmo_carpet_wave",
-    "floortile_cozmo_tile_diamond",
-    "floortile_cozmo_wood_bamboo",
-    "floortile_cozmo_wood_pattern",
-    "floortile_foundry_concrete_warning_cross",
-    "floortile_foundry_concrete_warning_h",
-    "floortile_foundry_concrete_warning_v",
-    "floortile_foundry_metal_plate",
-    "floortile_foundry_tile_metal",
-    "floortile_foundry_wood_black",
-    "floortile_global_sidewalk",
-    "floortile_global_sidewalk_grooves",
-    "floortile_luxury_carpet_edging_cross",
-    "floortile_luxury_carpet_goldpattern",
-    "floortile_luxury_carpet_whitepattern",
-    "floortile_luxury_tile_diagonal",
-    "floortile_luxury_tile_lobby",
-    "floortile_neon_bathtile",
-    "floortile_neon_carpet_polka",
-    "floortile_neon_dancefloor",
-    "floortile_neon_star",
-    "floortile_neon_swirl",
-    "floortile_rooftop_blacktar",
-    "floortile_rooftop_concrete",
-    "floortile_rooftop_tile",
-    "floortile_rooftop_watertower",
-    "floortile_rooftop_wood_pallet",
-    "floortile_skater_bathtile",
-    "floortile_skater_carpet_plain",
-    "floortile_skater_mallmarble",
-    "floortile_skater_malltile",
-    "floortile_skater_subfloor",
-    "floortile_subway_platformrunner_dark",
-    "floortile_subway_runner_cross",
-    "floortile_subway_runner_horizontal",
-    "floortile_subway_runner_vertical",
-    "floortile_subway_tile",
-    "floortile_wiseguy_carpet_velvet",
-    "floortile_wiseguy_diagonalwood",
-    "floortile_wiseguy_hardwood",
-    "floortile_wiseguy_hextile",
-    "floortile_wiseguy_wood_rough",
-    "fm_cat_tabby",
-    "forklift_side",
-    "fountain_brick_grind",
-    "gagmia_simore_espresso_machine_00_search",
-    "gagmia_simore_espresso_machine_01",
-    "game_craps_table",
-    "game_craps_table_llama",
-    "game_dartboard",
-    "game_drivingrange",
-    "game_fightcage_poster",
-    "game_fightcage_poster1",
-    "game_fightcage_sm",
-    "game_fightcage_walls",
-    "game_pingpong_paddle",
-    "game_robobattle_floor",
-    "game_robobattle_post",
-    "game_robobattle_robots",
-    "game_robobattle_wall_wood",
-    "gangster_bling_cas",
-    "gangster_bottles1_cas",
-    "gangster_bottles2_cas",
-    "gangster_shoe_cas",
-    "global_bamboo_dead",
-    "global_bamboo_top",
-    "global_bedsheet_pillow_set",
-    "global_biker_chrome",
-    "global_bolt",
-    "global_brass",
-    "global_cardboard",
-    "global_concrete_trim",
-    "global_concrete_wall",
-    "global_concrete_wall02",
-    "global_concrete_wall03",
-    "global_concreteblk01",
-    "global_copper",
-    "global_cushion_canvas_blue",
-    "global_cushion_fabric_cream",
-    "global_cushion_fabric_hippy",
-    "global_cushion_leather_dark",
-    "global_cushion_leather_old",
-    "global_cushion_leather_red",
-    "global_cushion_pattern_grn",
-    "global_cushion_zebra",
-    "global_decorative_japanese",
-    "global_decorative_japanese01",
-    "global_decorative_japanese02",
-    "global_foundry_warning_label",
-    "global_foundry_welded_metal",
-    "global_foundry_welded_metal_cas",
-    "global_framecorner_dark",
-    "global_framecorner_dark_cas",
-    "global_fridge_bits",
-    "global_glass_green",
-    "global_glass_red",
-    "global_gold_pat_1",
-    "global_gold_pat_2",
-    "global_gold_pat_3",
-    "global_gold_pat_3_cas",
-    "global_gold_pat_3_end",
-    "global_gold_pat_5",
-    "global_gold_pat_6",
-    "global_gold_pat_6_cas",
-    "global_gold_pat_7",
-    "global_gold_plating",
-    "global_gold_reflective",
-    "global_gold_reflective_cas",
-    "global_hottub_speckle",
-    "global_marble",
-    "global_marble_02",
-    "global_marble_maroon",
-    "global_metal_diamondgraphite",
-    "global_metal_diamondplate",
-    "global_metal_diamondplate_dull",
-    "global_metal_diamondplate_dull_cas",
-    "global_metal_dot",
-    "global_metal_dot_cas",
-    "global_metal_dot_drk",
-    "global_metal_shiny",
-    "global_metal_siding02",
-    "global_metal_with_holes",
-    "global_metalsiding01",
-    "global_mirror",
-    "global_molding_checker_4x",
-    "global_molding_metal_brushed",
-    "global_molding_wood_beam_desat",
-    "global_molding_wood_decorative_01",
-    "global_molding_wood_white",
-    "global_picframemtl",
-    "global_picframemtl_cas",
-    "global_plastic_black",
-    "global_plastic_blue_speckle",
-    "global_plastic_red_speckle",
-    "global_plywood",
-    "global_pole_lite",
-    "global_rebar",
-    "global_sink_steel",
-    "global_sonyeyebkpanel",
-    "global_stainless_steel_panel",
-    "global_velvet_wiseguys",
-    "global_victorian_marble",
-    "global_victorian_marble_cas",
-    "global_vinyl_rib",
-    "global_warning_stripe",
-    "global_warning_stripe_cas",
-    "global_wood_black",
-    "global_wood_brown",
-    "global_wood_charred",
-    "global_wood_dark",
-    "global_wood_light",
-    "global_wood_neon",
-    "global_wood_old",
-    "global_wood_red",
-    "global_wood_red_cas",
-    "global_wood_screws",
-    "global_wood_slats02",
-    "global_wood_slats02_cas",
-    "gradient_middle_grey",
-    "gradient_straight",
-    "gravel_64",
-    "gravel_gradient",
-    "grey",
-    "halogen_heaven_lamp_by_contempto",
-    "helipad",
-    "hipster_bottles1_cas",
-    "hipster_bottles2_cas",
-    "hipster_ceilinghookbase_cas",
-    "hipster_ceilinghookcaution_cas",
-    "hipster_ceilinghookmetal_cas",
-    "hipster_ceilinghooktube_cas",
-    "hipster_frame_cas",
-    "hipsters_floor_cas",
-    "hipsters_wall_cas",
-    "honda_storefront",
-    "horror_channel_010000",
-    "horror_channel_010001",
-    "horror_channel_010002",
-    "horror_channel_010003",
-    "horror_channel_010004",
-    "horror_channel_010005",
-    "horror_channel_010006",
-    "horror_channel_010007",
-    "horror_channel_010008",
-    "horror_channel_010009",
-    "horror_channel_010010",
-    "horror_channel_010011",
-    "horror_channel_010012",
-    "horror_channel_010013",
-    "horror_channel_010014",
-    "horror_channel_010015",
-    "house_load0",
-    "house_load1",
-    "house_load10",
-    "house_load2",
-    "house_load3",
-    "house_load4",
-    "house_load5",
-    "house_load6",
-    "house_load7",
-    "house_load8",
-    "house_load9",
-    "icon_cash",
-    "jewel_black",
-    "jewel_blue",
-    "jewel_red",
-    "job_bartender_panels",
-    "job_chop_shop_bench",
-    "job_fashion_photo",
-    "job_fashion_photo_backdrop",
-    "job_ferret_box",
-    "job_ferret_tamer",
-    "job_ferret_tamer_bag",
-    "job_ferret_tamer_pallet",
-    "job_fireworks",
-    "job_fireworks_burned",
-    "job_iron_sculptor",
-    "job_iron_sculptor_molten_iron",
-    "job_sushi_whizz_ingredients",
-    "kicktail_cinder_tile",
-    "kicktail_matress",
-    "kicktail_metal_02",
-    "kicktail_metal_06",
-    "kicktail_sheet_and_blanket",
-    "kicktail_wood_01",
-    "kicktail_wood_01_cas",
-    "kicktail_wood_03_cas",
-    "kicktail_wood_2x4",
-    "lamp_floor_02",
-    "lamp_floor_barrel",
-    "lamp_floor_flame_cannon",
-    "lamp_floor_flame_cannon_bolt",
-    "lamp_floor_flame_cannon_gas",
-    "lamp_floor_flame_cannon_plate",
-    "lamp_floor_floodlight",
-    "lamp_floor_gasheater",
-    "lamp_floor_haight",
-    "lamp_floor_haight_shade",
-    "lamp_wall_01",
-    "lamp_wall_traditonal",
-    "loading_subway_map",
-    "logo_trash",
-    "map_1",
-    "map_2",
-    "maple_door_frame",
-    "menubevel b - r",
-    "metal",
-    "metal_barrel",
-    "metal_cargo",
-    "metal_cas",
-    "metal_corrugated",
-    "metal_corrugated_dirty",
-    "metal_generic_chrome",
-    "metal_rivot",
-    "metalcollar_cas",
-    "mm_monkey",
-    "molding_maple",
-    "musician_floor_cas",
-    "musician_posters_cas",
-    "musician_roof_cas",
-    "musician_wall_cas",
-    "musicians_bottles1_cas",
-    "musicians_bottles2_cas",
-    "musicians_frame_cas",
-    "musicians_frameL_cas",
-    "narcisco_floor_mirror",
-    "neon_blue",
-    "neon_blue_cas",
-    "neon_green",
-    "neon_green_cas",
-    "neon_lights_cas",
-    "neon_purple",
-    "neon_ravetower",
-    "neon_red",
-    "neon_red_cas",
-    "neon_white",
-    "neon_white_cas",
-    "neon_white_dim",
-    "neon_yellow",
-    "neon_yellow_cas",
-    "neongreen",
-    "new_cursor_03",
-    "new_cursor_03_buildmode",
-    "new_cursor_buymode",
-    "nightclub_counter_molding",
-    "nothing",
-    "npc_catwoman",
-    "npc_chinese_food_deliveryman",
-    "npc_darius_body",
-    "npc_hint_robotperformer",
-    "npc_skill_creative_female",
-    "npc_skill_creative_male",
-    "npc_thief_room",
-    "nps_captain_catastrophe",
-    "numica_counter_face",
-    "numica_counter_side",
-    "numica_counter_top",
-    "pachinko_metal",
-    "pachinko_plate_01",
-    "performance_stage_blue",
-    "performance_stage_curtain",
-    "performance_stage_wood",
-    "personal_wood_cas",
-    "piercing_l_ear_diamond_single_arrow",
-    "piercing_l_ear_diamond_single_dolphine",
-    "piercing_l_ear_diamond_single_ring",
-    "piercing_l_ear_gold_single",
-    "piercing_lip_diamond_triple_large",
-    "piercing_navel_diamond_ring",
-    "piercing_navel_diamond_stud",
-    "piercing_nipple_diamond_ring",
-    "piercing_nose_diamond_bar",
-    "pillar_small_green",
-    "placeholder",
-    "plant_floor_cactus",
-    "plant_floor_fern_base",
-    "plate_of_food(empty)",
-    "plate_of_food(full)",
-    "plate_of_food(half-empty)",
-    "plumbing_bathtub_jetted",
-    "plumbing_floor_sink_foundry",
-    "plumbing_fountain_dh",
-    "plumbing_fountain_stones",
-    "plumbing_hottub_2x3_frame",
-    "plumbing_hydrant",
-    "plumbing_hydrant_variant",
-    "plumbing_sink_counter_retro",
-    "plumbing_sink_floor_filthy",
-    "plumbing_sink_floor_ornate",
-    "plumbing_toilet_foundry",
-    "plumbing_toilet_foundry_metal",
-    "plumbing_toilet_ornate",
-    "plumbing_toilet_public_bowl",
-    "plumbing_toilet_public_seat",
-    "plumbing_toilet_public_waste",
-    "plumbing_toilet_stall_bowl",
-    "plumbing_toilet_stall_walls",
-    "pluming_shower_marble",
-    "plumming_sink_floor_public_back",
-    "plumming_sink_floor_public_drain",
-    "plumming_sink_floor_public_faucet_blue",
-    "plumming_sink_floor_public_faucet_red",
-    "plumming_sink_floor_public_pipe_hole",
-    "powercable",
-    "powersocials_chugalug_can",
-    "powersocials_xamshot_camera",
-    "prop_banana",
-    "prop_baseball_bat",
-    "prop_bbq_spatula",
-    "prop_billiards",
-    "prop_blastikiss_breath_spray",
-    "prop_bone",
-    "prop_book",
-    "prop_bottle",
-    "prop_cat_catnip",
-    "prop_cat_fish_skeleton",
-    "prop_cat_fish_whole",
-    "prop_coin",
-    "prop_colacan",
-    "prop_critter_blue",
-    "prop_critter_red",
-    "prop_dollar",
-    "prop_donut",
-    "prop_dustpan",
-    "prop_dustpanash",
-    "prop_energycan",
-    "prop_espresso_cup",
-    "prop_extinguisher",
-    "prop_firecrackers",
-    "prop_foamhand",
-    "prop_gamble_dice",
-    "prop_game_drivingrange_ball_propa",
-    "prop_game_drivingrange_club_left_hand",
-    "prop_gift_box",
-    "prop_gnome_tools",
-    "prop_handbroom",
-    "prop_hobostick",
-    "prop_hoverboard",
-    "prop_job_fashion_photo",
-    "prop_job_ferret",
-    "prop_job_sushi_whizz_plate",
-    "prop_job_sushi_whizz_plate_spoiled",
-    "prop_juggle",
-    "prop_knife",
-    "prop_microphone",
-    "prop_money",
-    "prop_monkey_arm",
-    "prop_mop",
-    "prop_newspaper",
-    "prop_nightstick",
-    "prop_plunger",
-    "prop_powerchord_grate",
-    "prop_powerchord_guitar_left_handprop1",
-    "prop_powerchord_speaker",
-    "prop_powerchord_speakerfelt",
-    "prop_reaper_scythe",
-    "prop_redbullcan",
-    "prop_redbullcan_02",
-    "prop_remote",
-    "prop_ringbox",
-    "prop_robothand",
-    "prop_screwdriver",
-    "prop_scrubbrush",
-    "prop_skateboard",
-    "prop_sledgehammer",
-    "prop_socials_stinkbomb",
-    "prop_sock",
-    "prop_soda_can",
-    "prop_softball",
-    "prop_spit",
-    "prop_sponge",
-    "prop_spoon",
-    "prop_spraycan",
-    "prop_strobe",
-    "prop_towelwhip",
-    "prop_trashbag",
-    "prop_tumbler",
-    "prop_umbrella",
-    "prop_utensils",
-    "prop_wateringcan",
-    "prop_wine_bottle",
-    "prop_wrench",
-    "prop_xam",
-    "punk_curtain_cas",
-    "rail_rubber_cas",
-    "raver_wall_cas",
-    "redbull_small",
-    "redbull_small_02",
-    "reflect_test",
-    "reflection_leather",
-    "reflection_stainless_steel",
-    "rep_01",
-    "rep_01_shader_poster",
-    "rep_02",
-    "rep_02_shader_floor_ad",
-    "rep_02_shader_wall_ad",
-    "rep_03",
-    "rep_03_shader_floor_ad_02",
-    "rep_03_shader_graffiti",
-    "rep_03_shader_wall_ad_02",
-    "rep_03_shader_wall_machine",
-    "rep_04",
-    "rep_04_honda",
-    "rep_04_shader_painting",
-    "rep_04_shader_tv",
-    "rep_04_verizon",
-    "rep_05",
-    "rep_05_shader_billboard",
-    "rep_05_shader_honda",
-    "rep_05_shader_jumbotron",
-    "rep_05_shader_verizon",
-    "ringmaker",
-    "romance_01",
-    "romance_02",
-    "romance_03",
-    "romance_04",
-    "romance_05",
-    "romance_06",
-    "romance_07",
-    "romance_08",
-    "romance_09",
-    "romance_10",
-    "roof_biker_metal_red_cas",
-    "roof_black_01",
-    "roof_ceramic_tile",
-    "roof_ceramic_tile02",
-    "roof_tan_02",
-    "roof_tan_03",
-    "roof_tile_japanese",
-    "roofing_flat01",
-    "roulette_tabletop",
-    "roulette_wheel",
-    "rug_deco_2x2",
-    "rug_japanese",
-    "rug_leopard",
-    "rug_modern_2x2",
-    "rug_native_2x1",
-    "rug_tiger_2x1",
-    "SailBoat",
-    "satellitedish",
-    "sausagepackage",
-    "scenery_dumpster",
-    "scenery_dumpster_small",
-    "scenery_floor_antenna",
-    "scenery_floor_clothesline",
-    "scenery_floor_orangecone",
-    "scenery_floor_roofvent",
-    "scenery_floor_ventpipe",
-    "scenery_neon_walkway",
-    "scenery_newspaper_machine",
-    "scenery_parkingmeter",
-    "scenery_pillar_brick",
-    "scenery_pillar_ibeam",
-    "scenery_plank_wood",
-    "scenery_plank_wood_cas",
-    "scenery_sidewalk_grate",
-    "scenery_streetdetail_set01",
-    "scenery_traffic_barge",
-    "scenery_traffic_barge_containers",
-    "scum",
-    "seating_bench_public_2x1",
-    "seating_carseat",
-    "seating_chair_scootable_foundry",
-    "seating_chair_scootable_futon",
-    "seating_chair_scootable_futon_cas",
-    "seating_chair_scootable_ornate",
-    "seating_chair_scootable_ornate_cas",
-    "seating_chair_scootable_wiseguy",
-    "seating_chair_stationary_biker",
-    "seating_chair_stationary_wiseguy",
-    "seating_chair_stationary_wiseguy_cas",
-    "seating_diner_2x1",
-    "seating_ornate_2x1_arm",
-    "seating_ornate_2x1_arm_cas",
-    "seating_ornate_2x1_goldvelvet_buttons",
-    "seating_ornate_2x1_goldvelvet_buttons_cas",
-    "seating_ornate_velvet",
-    "seating_ornate_velvet_cas",
-    "seating_punk",
-    "seating_punk_cas",
-    "seating_sofa_chair_x_cream_action_queue",
-    "seating_sofa_couch_stainedplaid",
-    "seating_sofa_couch_x_cream_action_queue",
-    "seating_wiseguys_2x1",
-    "shop_125_cas",
-    "shop_counter_cashregister_1x1",
-    "shop_display_125_cas",
-    "shop_rack_cas",
-    "shower_curtain",
-    "shower_curtain_cas",
-    "shower_gold_tile",
-    "sidewalk_curb_grey",
-    "sidewalk_tile_01",
-    "sidewalk_tile_02_solid",
-    "sims_wall_paper_001",
-    "skater_bottles1_cas",
-    "skater_bottles2_cas",
-    "skaters_plank_wood01_cas",
-    "skill_creative_robot",
-    "skill_creative_schnizzlator",
-    "skill_mental",
-    "skill_mental_head",
-    "skill_upgrade_creative_1to2",
-    "skill_upgrade_creative_2to3",
-    "skill_upgrade_mental_1to2",
-    "skill_upgrade_mental_2to3",
-    "skill_upgrade_physical_1to2",
-    "skill_upgrade_physical_2to3",
-    "skydome_gradient",
-    "sportbikengine",
-    "stage_fashionrunway_lights",
-    "stairs",
-    "stairs_cas",
-    "steel_64",
-    "steel_torii_door",
-    "steel_torii_door_cas",
-    "stockticker_01",
-    "stockticker_02",
-    "street_stripe",
-    "stripe_yellow",
-    "subway_carceiling_cas",
-    "subway_carframe_cas",
-    "subway_carframejamb_cas",
-    "subway_map",
-    "subway_map_brick",
-    "subway_pillar_cas",
-    "subway_roof",
-    "subway_seats_cas",
-    "subway_track_base",
-    "table_armwrestling",
-    "table_cardtable_1x1",
-    "table_cardtable_1x1_cas",
-    "table_foundry_1x1",
-    "table_foundry_1x1_cas",
-    "table_foundry_2x1",
-    "table_japanese_flower",
-    "tattoo_case_cas",
-    "tattoo_ink_cas",
-    "tattoo_parlor_entrance",
-    "tattoo_sign",
-    "tattoo_sign_cas",
-    "tattooboard_cas",
-    "taxi_headlights",
-    "technos_bottles1b_cas",
-    "technos_bottles2b_cas",
-    "technos_bottles3b_cas",
-    "technos_floor_cas",
-    "technos_frame_cas",
-    "terrain_mom_house_detail",
-    "tile_blue",
-    "tile_pattern_blue",
-    "tile_seat",
-    "tiled_counter_face",
-    "tiled_counter_side",
-    "tiled_counter_top",
-    "tracklight_gangster125_cas",
-    "trailer_metal",
-    "train_01",
-    "train_tanker",
-    "train_tracks",
-    "transition_cas_armoire_neon",
-    "transition_cas_armoire_ornate",
-    "transition_cas_armoire_ornate_door",
-    "transition_cas_armoire_toolchest",
-    "transition_cas_armoire_wiseguys",
-    "transition_cas_dresser",
-    "transition_cas_gamecube_dresser",
-    "transition_cas_truck",
-    "transition_elevator_steel",
-    "transition_subwaystairs_roof",
-    "trash_ash_search",
-    "trashcan_chimney",
-    "trashcan_chimney_tileable",
-    "travelmap1",
-    "trottco_27_inch_color_television-(screen)-00",
-    "trottco_27_inch_color_television-(screen)-05",
-    "trottco_27_inch_color_television-(screen)-06",
-    "trottco_27_inch_color_television-(screen)-07",
-    "trottco_27_inch_color_television-(screen)-08",
-    "turd_water",
-    "tutorial_rep_icon",
-    "tv_screen_search",
-    "twitchomatic_skill_zonebar",
-    "ui_creditscreen",
-    "ui_mainmenuscreen",
-    "ui_online_conn",
-    "ui_screen_gradient",
-    "ui_start_screen",
-    "umbrellatowel",
-    "vehicle_honda_civic",
-    "vehicle_honda_element_small",
-    "vehicle_honda_s2000",
-    "vehicle_japanese_compact_silver",
-    "vehicle_paint_red",
-    "vehicle_verizonvan",
-    "vehicle_verizonvan_02",
-    "vehicle_windshield",
-    "verizon_logolong",
-    "verizon_phoneDisplay",
-    "verizon_storedisplaywall01",
-    "wall_125_orange_cas",
-    "wall_black",
-    "wall_fashion_white",
-    "wallpaper_125_cas",
-    "wallpaper_apt1_gold_wainscot",
-    "wallpaper_apt1_grey_wainscot",
-    "wallpaper_apt1_plain_white",
-    "wallpaper_apt1_tile_hex",
-    "wallpaper_apt1_wood_wainscot",
-    "wallpaper_apt2_brick_blocky",
-    "wallpaper_apt2_cinderblock",
-    "wallpaper_apt2_derelict",
-    "wallpaper_apt2_sheetmetal_supports",
-    "wallpaper_apt2_tile_bath",
-    "wallpaper_apt3_brick_brown_small",
-    "wallpaper_apt3_brick_concrete",
-    "wallpaper_apt3_brick_decorative",
-    "wallpaper_apt3_brick_pattern",
-    "wallpaper_apt3_psychedelic",
-    "wallpaper_apt_concrete_derelict",
-    "wallpaper_apt_concrete_plain",
-    "wallpaper_apt_japanese_woodrim",
-    "wallpaper_apt_japanese_woodsolid",
-    "wallpaper_biker_bar",
-    "wallpaper_biker_black",
-    "wallpaper_biker_metal_cas",
-    "wallpaper_biker_metal_red",
-    "wallpaper_biker_metal_red_var_01",
-    "wallpaper_biker_wainscot_leather",
-    "wallpaper_biker_wood_black",
-    "wallpaper_biker_wood_black_var_01",
-    "wallpaper_brick_biege",
-    "wallpaper_brick_decorative_var_01",
-    "wallpaper_brick_plain",
-    "wallpaper_concrete",
-    "wallpaper_concrete_cas",
-    "wallpaper_concrete_grime_cas",
-    "wallpaper_concrete_poured",
-    "wallpaper_cozmo_glass",
-    "wallpaper_cozmo_stone",
-    "wallpaper_cozmo_stone_var_01",
-    "wallpaper_cozmo_tile_diamond",
-    "wallpaper_cozmo_velvet_pattern",
-    "wallpaper_cozmo_wood_pattern",
-    "wallpaper_derelict_01",
-    "wallpaper_derelict_02",
-    "wallpaper_derelict_03",
-    "wallpaper_foundry_concrete",
-    "wallpaper_foundry_concrete_wainscot",
-    "wallpaper_foundry_metal_corrugated",
-    "wallpaper_foundry_metal_corrugated_var_01",
-    "wallpaper_foundry_rough",
-    "wallpaper_foundry_sheetmetal",
-    "wallpaper_japanese_woodrim",
-    "wallpaper_luxury_gold_embossed",
-    "wallpaper_luxury_gold_wainscot",
-    "wallpaper_luxury_marble_gold",
-    "wallpaper_luxury_metal_gold",
-    "wallpaper_luxury_offwhite",
-    "wallpaper_luxury_offwhite_var_01",
-    "wallpaper_neon_bathtile",
-    "wallpaper_neon_black",
-    "wallpaper_neon_black_var_01",
-    "wallpaper_neon_black_wainscot",
-    "wallpaper_neon_plain",
-    "wallpaper_neon_plain_var_01",
-    "wallpaper_neon_redtorii",
-    "wallpaper_paisley",
-    "wallpaper_plain",
-    "wallpaper_plain_blue",
-    "wallpaper_plain_tile_hex_10wide",
-    "wallpaper_plain_white",
-    "wallpaper_psycadelic",
-    "wallpaper_rooftop_brick",
-    "wallpaper_rooftop_brick_var_01",
-    "wallpaper_rooftop_stucco",
-    "wallpaper_rooftop_stucco_var_01",
-    "wallpaper_rooftop_tarpaper",
-    "wallpaper_rooftop_tile",
-    "wallpaper_rooftop_watertower",
-    "wallpaper_sheetmetal",
-    "wallpaper_skater_bathtile",
-    "wallpaper_skater_malltile",
-    "wallpaper_skater_mallwall",
-    "wallpaper_skater_stucco",
-    "wallpaper_skater_stucco_var_01",
-    "wallpaper_skater_studs",
-    "wallpaper_skater_studs_var_01",
-    "wallpaper_stucco_cream",
-    "wallpaper_subway_brick",
-    "wallpaper_subway_brick_var_01",
-    "wallpaper_subway_concrete",
-    "wallpaper_subway_tile",
-    "wallpaper_subway_tile_pattern",
-    "wallpaper_subway_train",
-    "wallpaper_subwaycar",
-    "wallpaper_subwaycar_cas",
-    "wallpaper_subwaycar_window",
-    "wallpaper_tile_square_3wide",
-    "wallpaper_victorian_diamond_red",
-    "wallpaper_wiseguy_brick_brown_var_01",
-    "wallpaper_wiseguy_brick_wainscot",
-    "wallpaper_wiseguy_wainscot_black",
-    "wallpaper_wiseguy_wainscot_red",
-    "wallpaper_wiseguy_woodpanel",
-    "wallpaper_wiseguy_woodpanel_var_01",
-    "wallpaper_wood_plank_red",
-    "walls_down",
-    "walnut_door",
-    "white",
-    "wice_lite_cas",
-    "wice_ornatered_cas",
-    "window_brick_arch",
-    "Window_Framing",
-    "window_industrial01",
-    "window_industrial01_solid",
-    "window_outer_lot",
-    "window_outer_lot02",
-    "window_outer_lot03",
-    "window_rectangle_multipane",
-    "window_vent_fan",
-    "window_warehouse",
-    "window_warehouse_cas",
-    "windows_inside_02",
-    "windsor_door",
-    "wise_alum_cas",
-    "wise_box_cas",
-    "wise_cover_cas",
-    "wise_door_cas",
-    "wise_floorwood_cas",
-    "wise_frame_cas",
-    "wise_top_cas",
-    "wise_wood01_cas",
-    "wise_wood_cas",
-    "wiseguy_bottles1_cas",
-    "wiseguy_bottles2_cas",
-    "wiseguy_shoebox_cas",
-    "wood_crate",
-    "wood_generic_worn_edges",
-    "wood_pallet",
-    "wood_stack",
-    "wrappers",
-    "xlr8r_food_processor",
-    "yellow_gradient",
-];
+pub static SPECULAR_FILE_NAMES: std::sync::LazyLock<std::collections::HashSet<&'static str>> =
+    std::sync::LazyLock::new(|| crate::texture_flags::names(include_str!("../data/the_urbz_specular_file_names.txt")));
@@ -0,0 +1,218 @@
+use aes::cipher::{block_padding::NoPadding, BlockDecryptMut, KeyIvInit};
+
+const MAGIC_OFFSET: usize = 0x18;
+const MAGIC: u32 = 0x5D1C9EA3;
+const PARTITION_TABLES_OFFSET: usize = 0x40000;
+const PARTITION_HEADER_LENGTH: usize = 0x2C0;
+const CLUSTER_LENGTH: usize = 0x8000;
+const CLUSTER_DATA_OFFSET: usize = 0x400;
+const CLUSTER_DATA_LENGTH: usize = CLUSTER_LENGTH - CLUSTER_DATA_OFFSET;
+
+pub fn is_image(image: &dyn crate::disc::Image) -> crate::Result<bool> {
+    crate::disc::has_signature(image, MAGIC_OFFSET, &MAGIC.to_be_bytes())
+}
+
+pub fn load_common_key(path: &std::path::Path) -> crate::Result<[u8; 16]> {
+    let bytes = std::fs::read(path).map_err(|error| crate::Error::io(path, error))?;
+    if let Ok(key) = <[u8; 16]>::try_from(bytes.as_slice()) {
+        return Ok(key);
+    }
+
+    let text = String::from_utf8_lossy(&bytes);
+    let text = text.trim();
+    let mut key = [0; 16];
+    if text.len() != 32 || !text.is_ascii() {
+        return Err(crate::Error::InvalidCommonKey { path: path.to_owned() });
+    }
+    for (i, byte) in key.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&text[i * 2..i * 2 + 2], 16)
+            .map_err(|_| crate::Error::InvalidCommonKey { path: path.to_owned() })?;
+    }
+
+    Ok(key)
+}
+
+fn decrypt(key: &[u8; 16], iv: &[u8; 16], bytes: &mut [u8]) {
+    cbc::Decryptor::<aes::Aes128>::new(key.into(), iv.into())
+        .decrypt_padded_mut::<NoPadding>(bytes)
+        .expect("encrypted data is a multiple of the AES block size");
+}
+
+fn data_partition_offset(image: &dyn crate::disc::Image) -> crate::Result<usize> {
+    let tables = image.read(PARTITION_TABLES_OFFSET, 4 * 8)?;
+    for table in tables.chunks_exact(8) {
+        let partition_count = crate::bytes::u32(table, 0, crate::Endianness::Big)? as usize;
+        let table_offset = (crate::bytes::u32(table, 4, crate::Endianness::Big)? as usize) << 2;
+        let partitions = image.read(table_offset, partition_count.saturating_mul(8))?;
+        for partition in partitions.chunks_exact(8) {
+            if crate::bytes::u32(partition, 4, crate::Endianness::Big)? == 0 {
+                return Ok((crate::bytes::u32(partition, 0, crate::Endianness::Big)? as usize) << 2);
+            }
+        }
+    }
+
+    Err(crate::Error::InvalidDiscImage {
+        reason: "no data partition",
+    })
+}
+
+pub struct Partition<'a> {
+    image: Box<dyn crate::disc::Image + 'a>,
+    data_offset: usize,
+    length: usize,
+    title_key: [u8; 16],
+    cache: crate::disc::BlockCache,
+}
+
+impl<'a> Partition<'a> {
+    pub fn open(image: Box<dyn crate::disc::Image + 'a>, common_key: &[u8; 16]) -> crate::Result<Partition<'a>> {
+        let offset = data_partition_offset(image.as_ref())?;
+        let header = image.read(offset, PARTITION_HEADER_LENGTH)?;
+
+        let mut title_key = crate::bytes::array::<16>(&header, 0x1BF)?;
+        let mut title_key_iv = [0; 16];
+        title_key_iv[..8].copy_from_slice(crate::bytes::slice(&header, 0x1DC, 8)?);
+        decrypt(common_key, &title_key_iv, &mut title_key);
+
+        let data_offset = offset + ((crate::bytes::u32(&header, 0x2B8, crate::Endianness::Big)? as usize) << 2);
+        let data_length = (crate::bytes::u32(&header, 0x2BC, crate::Endianness::Big)? as usize) << 2;
+        drop(header);
+
+        let partition = Partition {
+            image,
+            data_offset,
+            length: data_length / CLUSTER_LENGTH * CLUSTER_DATA_LENGTH,
+            title_key,
+            cache: Default::default(),
+        };
+        if !is_image(&partition)? {
+            return Err(crate::Error::WrongCommonKey);
+        }
+
+        Ok(partition)
+    }
+
+    fn decrypt_cluster(&self, index: usize) -> crate::Result<Vec<u8>> {
+        let cluster = self.image.read(self.data_offset + index * CLUSTER_LENGTH, CLUSTER_LENGTH)?;
+        let iv = crate::bytes::array::<16>(&cluster, 0x3D0)?;
+        let mut data = cluster[CLUSTER_DATA_OFFSET..].to_vec();
+        decrypt(&self.title_key, &iv, &mut data);
+        Ok(data)
+    }
+}
+
+impl crate::disc::Image for Partition<'_> {
+    fn length(&self) -> usize {
+        self.length
+    }
+
+    fn read(&self, offset: usize, length: usize) -> crate::Result<std::borrow::Cow<'_, [u8]>> {
+        self.cache.read(offset, length, self.length, CLUSTER_DATA_LENGTH, |index| {
+            self.decrypt_cluster(index)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aes::cipher::{BlockEncryptMut, KeyIvInit};
+
+    const COMMON_KEY: [u8; 16] = [7; 16];
+    const TITLE_KEY: [u8; 16] = [9; 16];
+    const PARTITION_OFFSET: usize = 0x50000;
+
+    fn encrypt(key: &[u8; 16], iv: &[u8; 16], bytes: &mut [u8]) {
+        let length = bytes.len();
+        cbc::Encryptor::<aes::Aes128>::new(key.into(), iv.into())
+            .encrypt_padded_mut::<super::NoPadding>(bytes, length)
+            .unwrap();
+    }
+
+    fn image(data: &[u8]) -> Vec<u8> {
+        let cluster_count = data.len().div_ceil(super::CLUSTER_DATA_LENGTH);
+        let data_offset = PARTITION_OFFSET + 0x8000;
+        let mut image = vec![0; data_offset + cluster_count * super::CLUSTER_LENGTH];
+        image[super::MAGIC_OFFSET..super::MAGIC_OFFSET + 4].copy_from_slice(&super::MAGIC.to_be_bytes());
+
+        let tables = super::PARTITION_TABLES_OFFSET;
+        image[tables + 8..tables + 12].copy_from_slice(&1u32.to_be_bytes());
+        image[tables + 12..tables + 16].copy_from_slice(&((tables as u32 + 0x20) >> 2).to_be_bytes());
+        image[tables + 0x20..tables + 0x24].copy_from_slice(&((PARTITION_OFFSET as u32) >> 2).to_be_bytes());
+
+        let header = &mut image[PARTITION_OFFSET..PARTITION_OFFSET + super::PARTITION_HEADER_LENGTH];
+        let mut title_key = TITLE_KEY;
+        let title_id = [1, 2, 3, 4, 5, 6, 7, 8];
+        encrypt(
+            &COMMON_KEY,
+            &[title_id, [0; 8]].concat().try_into().unwrap(),
+            &mut title_key,
+        );
+        header[0x1BF..0x1CF].copy_from_slice(&title_key);
+        header[0x1DC..0x1E4].copy_from_slice(&title_id);
+        header[0x2B8..0x2BC].copy_from_slice(&(0x8000u32 >> 2).to_be_bytes());
+        header[0x2BC..0x2C0].copy_from_slice(&((cluster_count * super::CLUSTER_LENGTH) as u32 >> 2).to_be_bytes());
+
+        let clusters = image[data_offset..].chunks_exact_mut(super::CLUSTER_LENGTH);
+        for (index, (cluster, data)) in clusters.zip(data.chunks(super::CLUSTER_DATA_LENGTH)).enumerate() {
+            let iv = [index as u8 + 1; 16];
+            cluster[0x3D0..0x3E0].copy_from_slice(&iv);
+            cluster[super::CLUSTER_DATA_OFFSET..super::CLUSTER_DATA_OFFSET + data.len()].copy_from_slice(data);
+            encrypt(&TITLE_KEY, &iv, &mut cluster[super::CLUSTER_DATA_OFFSET..]);
+        }
+
+        image
+    }
+
+    fn partition_data() -> Vec<u8> {
+        let mut data = (0..super::CLUSTER_DATA_LENGTH + 0x100).map(|i| (i % 251) as u8).collect::<Vec<_>>();
+        data[super::MAGIC_OFFSET..super::MAGIC_OFFSET + 4].copy_from_slice(&super::MAGIC.to_be_bytes());
+        data
+    }
+
+    #[test]
+    fn decrypts_across_clusters() {
+        let data = partition_data();
+        let image = image(&data);
+        let image = crate::disc::open(&image, Some(&COMMON_KEY)).unwrap();
+        let length = super::CLUSTER_DATA_LENGTH * 2;
+        assert_eq!(image.length(), length);
+
+        let start = super::CLUSTER_DATA_LENGTH - 0x10;
+        assert_eq!(*image.read(start, 0x20).unwrap(), data[start..start + 0x20]);
+    }
+
+    #[test]
+    fn wrong_or_missing_common_key() {
+        let image = image(&partition_data());
+        assert!(matches!(
+            crate::disc::open(&image, Some(&[8; 16])),
+            Err(crate::Error::WrongCommonKey)
+        ));
+        assert!(matches!(
+            crate::disc::open(&image, None),
+            Err(crate::Error::MissingCommonKey)
+        ));
+    }
+
+    #[test]
+    fn common_key_file() {
+        let directory = std::env::temp_dir().join(format!("tsc-texture-extractor-wii-{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+        let hex_path = directory.join("key.txt");
+        std::fs::write(&hex_path, "0707070707070707070707070707070a\n").unwrap();
+        let binary_path = directory.join("key.bin");
+        std::fs::write(&binary_path, COMMON_KEY).unwrap();
+        let invalid_path = directory.join("invalid.txt");
+        std::fs::write(&invalid_path, "07070707").unwrap();
+
+        let mut expected = COMMON_KEY;
+        expected[15] = 0x0A;
+        assert_eq!(super::load_common_key(&hex_path).unwrap(), expected);
+        assert_eq!(super::load_common_key(&binary_path).unwrap(), COMMON_KEY);
+        assert!(matches!(
+            super::load_common_key(&invalid_path),
+            Err(crate::Error::InvalidCommonKey { .. })
+        ));
+        std::fs::remove_dir_all(&directory).unwrap();
+    }
+}
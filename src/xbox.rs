@@ -7,158 +7,250 @@ fn compact(x: usize) -> usize {
     x
 }
 
-fn deswizzle_c8(
-    bytes: &[u8],
-    dimensions: usize,
-    output: &mut image::RgbaImage,
-    palette: &[u8],
-    x_offset: usize,
-    y_offset: usize,
-) {
-    for (pixel_index, palette_index) in bytes.iter().enumerate().take(dimensions * dimensions) {
-        let x = compact(pixel_index);
-        let y = compact(pixel_index >> 1);
-
-        let palette_index = usize::from(*palette_index);
-        let r = palette[palette_index * 4];
-        let g = palette[(palette_index * 4) + 1];
-        let b = palette[(palette_index * 4) + 2];
-        let a = palette[(palette_index * 4) + 3];
-        output.put_pixel(
-            (x + x_offset) as u32,
-            ((dimensions - 1 - y) + y_offset) as u32,
-            image::Rgba([r, g, b, a]),
-        );
+fn check_dimensions(width: usize, height: usize) -> crate::Result<()> {
+    if width.is_power_of_two() && height.is_power_of_two() {
+        Ok(())
+    } else {
+        Err(crate::Error::UnsupportedDimensions { width, height })
     }
 }
 
-pub fn decode_c8(bytes: &[u8], width: usize, height: usize, palette: &[u8]) -> image::RgbaImage {
-    let mut image = image::RgbaImage::new(width as _, height as _);
-
-    if width == height {
-        deswizzle_c8(bytes, width, &mut image, palette, 0, 0);
+fn check_block_dimensions(width: usize, height: usize) -> crate::Result<()> {
+    if width == 0 || height == 0 {
+        Err(crate::Error::UnsupportedDimensions { width, height })
+    } else {
+        Ok(())
     }
+}
 
-    if width > height {
-        let section_count = width / height;
-        for i in 0..section_count {
-            let section_bytes = &bytes[i * (height * height)..];
-            deswizzle_c8(section_bytes, height, &mut image, palette, i * height, 0);
-        }
-    }
+fn deswizzle<const N: usize>(
+    bytes: &[u8],
+    width: usize,
+    height: usize,
+    pixel_length: usize,
+    convert: impl Fn(&[u8]) -> [u8; N],
+) -> Vec<u8> {
+    let dimensions = width.min(height);
+    let section_count = width.max(height) / dimensions;
+
+    let mut pixels = vec![0; width * height * N];
+    let sections = bytes.chunks_exact(dimensions * dimensions * pixel_length).take(section_count);
+    for (section, section_bytes) in sections.enumerate() {
+        let (x_offset, y_offset) = if width >= height {
+            (section * dimensions, 0)
+        } else {
+            (0, (section_count - 1 - section) * dimensions)
+        };
 
-    if height > width {
-        let section_count = height / width;
-        for i in 0..section_count {
-            let section_bytes = &bytes[i * (width * width)..];
-            let section_height_offset = (section_count * width) - ((i + 1) * width);
-            deswizzle_c8(section_bytes, width, &mut image, palette, 0, section_height_offset);
+        for (i, pixel) in section_bytes.chunks_exact(pixel_length).enumerate() {
+            let x = compact(i) + x_offset;
+            let y = (dimensions - 1 - compact(i >> 1)) + y_offset;
+            let pixel_index = ((y * width) + x) * N;
+            pixels[pixel_index..pixel_index + N].copy_from_slice(&convert(pixel));
         }
     }
 
-    image
+    pixels
 }
 
-fn deswizzle_rgba8(bytes: &[u8], dimensions: usize, output: &mut image::RgbaImage, x_offset: usize, y_offset: usize) {
-    for i in 0..(dimensions * dimensions) {
-        let x = compact(i);
-        let y = compact(i >> 1);
+pub fn decode_palette(palette: &[u8], palette_count: usize) -> crate::Result<Vec<[u8; 4]>> {
+    let palette = crate::bytes::slice(palette, 0, palette_count * 4)?;
+    Ok(palette.chunks_exact(4).map(|color| [color[0], color[1], color[2], color[3]]).collect())
+}
 
-        let r = bytes[i * 4];
-        let g = bytes[(i * 4) + 1];
-        let b = bytes[(i * 4) + 2];
-        let a = bytes[(i * 4) + 3];
-        output.put_pixel(
-            (x + x_offset) as u32,
-            ((dimensions - 1 - y) + y_offset) as u32,
-            image::Rgba([r, g, b, a]),
-        );
-    }
+pub fn decode_c8_indices(bytes: &[u8], width: usize, height: usize) -> crate::Result<image::GrayImage> {
+    check_dimensions(width, height)?;
+    let bytes = crate::bytes::slice(bytes, 0, width * height)?;
+    let indices = deswizzle(bytes, width, height, 1, |index| [index[0]]);
+    Ok(image::GrayImage::from_raw(width as _, height as _, indices).unwrap())
 }
 
-pub fn decode_rgba8(bytes: &[u8], width: usize, height: usize) -> image::RgbaImage {
-    let mut image = image::RgbaImage::new(width as _, height as _);
+pub fn decode_c8(bytes: &[u8], width: usize, height: usize, palette: &[u8]) -> crate::Result<image::RgbaImage> {
+    let indices = decode_c8_indices(bytes, width, height)?;
+    let palette = decode_palette(palette, 256)?;
+    Ok(crate::texture::expand(&indices, &palette))
+}
 
-    if width == height {
-        deswizzle_rgba8(bytes, width, &mut image, 0, 0);
-    }
+pub fn decode_rgba8(bytes: &[u8], width: usize, height: usize) -> crate::Result<image::RgbaImage> {
+    check_dimensions(width, height)?;
+    let bytes = crate::bytes::slice(bytes, 0, width * height * 4)?;
+    let pixels = deswizzle(bytes, width, height, 4, |pixel| {
+        [pixel[0], pixel[1], pixel[2], pixel[3]]
+    });
+    Ok(image::RgbaImage::from_raw(width as _, height as _, pixels).unwrap())
+}
 
-    if width > height {
-        let section_count = width / height;
-        for i in 0..section_count {
-            let section_bytes = &bytes[i * (height * height) * 4..];
-            deswizzle_rgba8(section_bytes, height, &mut image, i * height, 0);
-        }
-    }
+pub fn decode_rgb5(bytes: &[u8], width: usize, height: usize) -> crate::Result<image::RgbaImage> {
+    check_dimensions(width, height)?;
+    let bytes = crate::bytes::slice(bytes, 0, width * height * 2)?;
+    let pixels = deswizzle(bytes, width, height, 2, |pixel| {
+        let bits = u16::from_le_bytes([pixel[0], pixel[1]]);
+
+        let r = (((bits & 0b0111_1100_0000_0000) >> 10) << 3) as u8;
+        let g = (((bits & 0b0000_0011_1110_0000) >> 5) << 3) as u8;
+        let b = ((bits & 0b0000_0000_0001_1111) << 3) as u8;
+
+        [r, g, b, 255]
+    });
+    Ok(image::RgbaImage::from_raw(width as _, height as _, pixels).unwrap())
+}
+
+fn decode_blocks(
+    bytes: &[u8],
+    width: usize,
+    height: usize,
+    format: texpresso::Format,
+) -> crate::Result<image::RgbaImage> {
+    check_block_dimensions(width, height)?;
+    let bytes = crate::bytes::slice(bytes, 0, format.compressed_size(width, height))?;
+    // texpresso writes whole block rows, so the last one is decoded into padding and cropped.
+    let padded_height = if height > 4 { height.next_multiple_of(4) } else { height };
+    let mut decompressed_pixels = vec![0u8; width * padded_height * 4];
+    format.decompress(bytes, width, padded_height, &mut decompressed_pixels);
+    decompressed_pixels.truncate(width * height * 4);
+    let mut image = image::RgbaImage::from_raw(width as u32, height as u32, decompressed_pixels).unwrap();
+    image::imageops::flip_vertical_in_place(&mut image);
+    Ok(image)
+}
+
+pub fn decode_bc1(bytes: &[u8], width: usize, height: usize) -> crate::Result<image::RgbaImage> {
+    decode_blocks(bytes, width, height, texpresso::Format::Bc1)
+}
 
-    if height > width {
-        let section_count = height / width;
-        for i in 0..section_count {
-            let section_bytes = &bytes[i * (width * width) * 4..];
-            let section_height_offset = (section_count * width) - ((i + 1) * width);
-            deswizzle_rgba8(section_bytes, width, &mut image, 0, section_height_offset);
+pub fn decode_bc2(bytes: &[u8], width: usize, height: usize) -> crate::Result<image::RgbaImage> {
+    decode_blocks(bytes, width, height, texpresso::Format::Bc2)
+}
+
+fn swizzle<P: image::Pixel>(image: &image::ImageBuffer<P, Vec<P::Subpixel>>) -> Vec<P> {
+    let (width, height) = (image.width() as usize, image.height() as usize);
+    let dimensions = width.min(height);
+    let section_count = width.max(height) / dimensions;
+
+    let mut pixels = Vec::new();
+    for section in 0..section_count {
+        let (x_offset, y_offset) = if width >= height {
+            (section * dimensions, 0)
+        } else {
+            (0, (section_count - 1 - section) * dimensions)
+        };
+
+        for i in 0..(dimensions * dimensions) {
+            let x = compact(i);
+            let y = compact(i >> 1);
+            pixels.push(*image.get_pixel((x + x_offset) as u32, ((dimensions - 1 - y) + y_offset) as u32));
         }
     }
 
-    image
+    pixels
 }
 
-fn deswizzle_rgb5(bytes: &[u8], dimensions: usize, output: &mut image::RgbaImage, x_offset: usize, y_offset: usize) {
-    for i in 0..(dimensions * dimensions) {
-        let x = compact(i);
-        let y = compact(i >> 1);
+pub fn encode_c8(indices: &image::GrayImage) -> crate::Result<Vec<u8>> {
+    check_dimensions(indices.width() as usize, indices.height() as usize)?;
+    Ok(swizzle(indices).into_iter().map(|index| index.0[0]).collect())
+}
 
-        let bits = u16::from_le_bytes(bytes[i * 2..(i * 2) + 2].try_into().unwrap());
+pub fn encode_rgba8(image: &image::RgbaImage) -> crate::Result<Vec<u8>> {
+    check_dimensions(image.width() as usize, image.height() as usize)?;
+    Ok(swizzle(image).into_iter().flat_map(|pixel| pixel.0).collect())
+}
 
-        let r = (((bits & 0b0111_1100_0000_0000) >> 10) << 3) as u8;
-        let g = (((bits & 0b0000_0011_1110_0000) >> 5) << 3) as u8;
-        let b = ((bits & 0b0000_0000_0001_1111) << 3) as u8;
+pub fn encode_rgb5(image: &image::RgbaImage) -> crate::Result<Vec<u8>> {
+    check_dimensions(image.width() as usize, image.height() as usize)?;
+    Ok(swizzle(image)
+        .into_iter()
+        .flat_map(|pixel| {
+            let [r, g, b, a] = pixel.0;
+            let bits =
+                (u16::from(r >> 3) << 10) | (u16::from(g >> 3) << 5) | u16::from(b >> 3) | (u16::from(a >= 128) << 15);
+            bits.to_le_bytes()
+        })
+        .collect())
+}
 
-        output.put_pixel(
-            (x + x_offset) as u32,
-            ((dimensions - 1 - y) + y_offset) as u32,
-            image::Rgba([r, g, b, 255]),
-        );
+pub fn encode_palette(colors: &[[u8; 4]]) -> Vec<u8> {
+    let mut palette = vec![0; 256 * 4];
+    for (i, color) in colors.iter().enumerate() {
+        palette[i * 4..(i * 4) + 4].copy_from_slice(color);
     }
+
+    palette
 }
 
-pub fn decode_rgb5(bytes: &[u8], width: usize, height: usize) -> image::RgbaImage {
-    let mut image = image::RgbaImage::new(width as _, height as _);
+fn compress(image: &image::RgbaImage, format: texpresso::Format) -> Vec<u8> {
+    let image = image::imageops::flip_vertical(image);
+    let (width, height) = (image.width() as usize, image.height() as usize);
 
-    if width == height {
-        deswizzle_rgb5(bytes, width, &mut image, 0, 0);
-    }
+    let mut bytes = vec![0; format.compressed_size(width, height)];
+    format.compress(image.as_raw(), width, height, texpresso::Params::default(), &mut bytes);
+    bytes
+}
+
+pub fn encode_bc1(image: &image::RgbaImage) -> Vec<u8> {
+    compress(image, texpresso::Format::Bc1)
+}
 
-    if width > height {
-        let section_count = width / height;
-        for i in 0..section_count {
-            let section_bytes = &bytes[i * (height * height) * 2..];
-            deswizzle_rgb5(section_bytes, height, &mut image, i * height, 0);
+pub fn encode_bc2(image: &image::RgbaImage) -> Vec<u8> {
+    compress(image, texpresso::Format::Bc2)
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn block_compressed_zero_dimensions() {
+        let bytes = [0; 64];
+        for (width, height) in [(0, 8), (8, 0), (0, 0)] {
+            assert!(matches!(
+                super::decode_bc1(&bytes, width, height),
+                Err(crate::Error::UnsupportedDimensions { .. })
+            ));
+            assert!(matches!(
+                super::decode_bc2(&bytes, width, height),
+                Err(crate::Error::UnsupportedDimensions { .. })
+            ));
         }
     }
 
-    if height > width {
-        let section_count = height / width;
-        for i in 0..section_count {
-            let section_bytes = &bytes[i * (width * width) * 2..];
-            let section_height_offset = (section_count * width) - ((i + 1) * width);
-            deswizzle_rgb5(section_bytes, width, &mut image, 0, section_height_offset);
-        }
+    #[test]
+    fn block_compressed_truncated() {
+        assert!(matches!(
+            super::decode_bc1(&[0; 24], 8, 8),
+            Err(crate::Error::Truncated { .. })
+        ));
+        assert!(matches!(
+            super::decode_bc2(&[0; 48], 8, 8),
+            Err(crate::Error::Truncated { .. })
+        ));
     }
 
-    image
-}
+    #[test]
+    fn block_compressed_round_trip() {
+        let image = image::RgbaImage::from_fn(4, 8, |_, y| {
+            if y < 4 {
+                image::Rgba([255, 0, 0, 255])
+            } else {
+                image::Rgba([0, 0, 255, 255])
+            }
+        });
+        assert_eq!(super::decode_bc1(&super::encode_bc1(&image), 4, 8).unwrap(), image);
+        assert_eq!(super::decode_bc2(&super::encode_bc2(&image), 4, 8).unwrap(), image);
+    }
 
-pub fn decode_bc1(bytes: &[u8], width: usize, height: usize) -> image::RgbaImage {
-    let mut decompressed_pixels = vec![0u8; width * height * 4];
-    texpresso::Format::Bc1.decompress(bytes, width, height, &mut decompressed_pixels);
-    let image = image::RgbaImage::from_raw(width as u32, height as u32, decompressed_pixels).unwrap();
-    image::imageops::flip_vertical(&image)
-}
+    #[test]
+    fn block_compressed_any_size() {
+        let image = image::RgbaImage::from_fn(12, 8, |x, _| image::Rgba([[0, 132, 255][x as usize / 4], 0, 255, 255]));
+        assert_eq!(super::decode_bc1(&super::encode_bc1(&image), 12, 8).unwrap(), image);
+        assert_eq!(super::decode_bc2(&super::encode_bc2(&image), 12, 8).unwrap(), image);
+        assert_eq!(super::decode_bc1(&[0; 4 * 8], 6, 6).unwrap().dimensions(), (6, 6));
+    }
+
+    #[test]
+    fn swizzle_round_trip() {
+        let image = image::RgbaImage::from_fn(16, 4, |x, y| image::Rgba([x as u8, y as u8, (x * y) as u8, 255]));
+        let bytes = super::encode_rgba8(&image).unwrap();
+        assert_eq!(super::decode_rgba8(&bytes, 16, 4).unwrap(), image);
 
-pub fn decode_bc2(bytes: &[u8], width: usize, height: usize) -> image::RgbaImage {
-    let mut decompressed_pixels = vec![0u8; width * height * 4];
-    texpresso::Format::Bc2.decompress(bytes, width, height, &mut decompressed_pixels);
-    let image = image::RgbaImage::from_raw(width as u32, height as u32, decompressed_pixels).unwrap();
-    image::imageops::flip_vertical(&image)
+        let indices = image::GrayImage::from_fn(4, 8, |x, y| image::Luma([(x + y * 4) as u8]));
+        let bytes = super::encode_c8(&indices).unwrap();
+        assert_eq!(super::decode_c8_indices(&bytes, 4, 8).unwrap(), indices);
+    }
 }
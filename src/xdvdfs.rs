@@ -0,0 +1,140 @@
+const SECTOR_LENGTH: usize = 2048;
+const MAGIC: &[u8] = b"MICROSOFT*XBOX*MEDIA";
+const PARTITION_OFFSETS: [usize; 4] = [0, 0x18300000, 0xFD90000, 0x2080000];
+
+fn partition_offset(image: &dyn crate::disc::Image) -> crate::Result<Option<usize>> {
+    for offset in PARTITION_OFFSETS {
+        if crate::disc::has_signature(image, offset + 32 * SECTOR_LENGTH, MAGIC)? {
+            return Ok(Some(offset));
+        }
+    }
+
+    Ok(None)
+}
+
+pub fn is_image(image: &dyn crate::disc::Image) -> crate::Result<bool> {
+    Ok(partition_offset(image)?.is_some())
+}
+
+struct Directory {
+    path: String,
+    offset: usize,
+    length: usize,
+}
+
+pub fn files(image: &dyn crate::disc::Image) -> crate::Result<Vec<crate::disc::File>> {
+    let partition_offset = partition_offset(image)?.ok_or(crate::Error::NotADiscImage)?;
+    let descriptor = image.read(partition_offset + 32 * SECTOR_LENGTH, SECTOR_LENGTH)?;
+    let root_sector = crate::bytes::u32(&descriptor, 20, crate::Endianness::Little)? as usize;
+    let root_length = crate::bytes::u32(&descriptor, 24, crate::Endianness::Little)? as usize;
+
+    let mut files = Vec::new();
+    let mut visited = std::collections::HashSet::new();
+    let mut directories = vec![Directory {
+        path: String::new(),
+        offset: partition_offset + root_sector * SECTOR_LENGTH,
+        length: root_length,
+    }];
+    while let Some(directory) = directories.pop() {
+        if directory.length == 0 || !visited.insert(directory.offset) {
+            continue;
+        }
+
+        let table = image.read(directory.offset, directory.length)?;
+        let mut pending = vec![0];
+        let mut visited_entries = std::collections::HashSet::new();
+        while let Some(entry_offset) = pending.pop() {
+            if !visited_entries.insert(entry_offset) {
+                continue;
+            }
+
+            let entry = crate::bytes::tail(&table, entry_offset)?;
+            let left = crate::bytes::u16(entry, 0, crate::Endianness::Little)?;
+            if left == 0xFFFF {
+                continue;
+            }
+
+            let right = crate::bytes::u16(entry, 2, crate::Endianness::Little)?;
+            let sector = crate::bytes::u32(entry, 4, crate::Endianness::Little)? as usize;
+            let length = crate::bytes::u32(entry, 8, crate::Endianness::Little)? as usize;
+            let attributes = crate::bytes::u8(entry, 12)?;
+            let name_length = usize::from(crate::bytes::u8(entry, 13)?);
+            let name = String::from_utf8_lossy(crate::bytes::slice(entry, 14, name_length)?);
+
+            for child in [left, right] {
+                if child != 0 {
+                    pending.push(usize::from(child) * 4);
+                }
+            }
+
+            let path = format!("{}/{name}", directory.path);
+            let offset = partition_offset + sector * SECTOR_LENGTH;
+            if attributes & 0x10 != 0 {
+                directories.push(Directory { path, offset, length });
+            } else {
+                files.push(crate::disc::File::new(image, path, offset, length)?);
+            }
+        }
+    }
+
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    fn entry(left: u16, right: u16, sector: u32, length: u32, directory: bool, name: &str) -> Vec<u8> {
+        let mut entry = [
+            &left.to_le_bytes()[..],
+            &right.to_le_bytes(),
+            &sector.to_le_bytes(),
+            &length.to_le_bytes(),
+            &[if directory { 0x10 } else { 0x20 }, name.len() as u8],
+            name.as_bytes(),
+        ]
+        .concat();
+        entry.resize(entry.len().next_multiple_of(4), 0xFF);
+        entry
+    }
+
+    fn image() -> Vec<u8> {
+        let sector = |index: usize| index * super::SECTOR_LENGTH;
+        let media = entry(0, 0, 35, 6, false, "textures.arc");
+        let root = [
+            entry(0, 5, 34, media.len() as u32, true, "media"),
+            entry(0, 0, 36, 4, false, "default.xbe"),
+        ]
+        .concat();
+
+        let mut image = vec![0; sector(37)];
+        image[sector(32)..sector(32) + 20].copy_from_slice(super::MAGIC);
+        image[sector(32) + 20..sector(32) + 24].copy_from_slice(&33u32.to_le_bytes());
+        image[sector(32) + 24..sector(32) + 28].copy_from_slice(&(root.len() as u32).to_le_bytes());
+        image[sector(33)..sector(33) + root.len()].copy_from_slice(&root);
+        image[sector(34)..sector(34) + media.len()].copy_from_slice(&media);
+        image[sector(35)..sector(35) + 6].copy_from_slice(b"arcarc");
+        image
+    }
+
+    #[test]
+    fn files() {
+        let image = image();
+        assert!(super::is_image(&image.as_slice()).unwrap());
+
+        let mut files = super::files(&image.as_slice()).unwrap();
+        files.sort_by(|a, b| a.path.cmp(&b.path));
+        let files = files.iter().map(|file| (file.path.as_str(), file.offset, file.length)).collect::<Vec<_>>();
+        assert_eq!(
+            files,
+            [("/default.xbe", 36 * 2048, 4), ("/media/textures.arc", 35 * 2048, 6)]
+        );
+    }
+
+    #[test]
+    fn not_an_image() {
+        assert!(!super::is_image(&[0; 0x20000].as_slice()).unwrap());
+        assert!(matches!(
+            super::files(&[0; 0x20000].as_slice()),
+            Err(crate::Error::NotADiscImage)
+        ));
+    }
+}